@@ -0,0 +1,27 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::packing_algorithm;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Builds a repeatable mix of item sizes, so that each benchmark packs the same items.
+fn items(count: usize) -> Vec<Item<'static>> {
+    let sizes = [[1, 2, 3], [2, 2, 2], [1, 1, 4], [3, 4, 5], [1, 1, 1]];
+    (0..count)
+        .map(|index| Item::new("item", sizes[index % sizes.len()]))
+        .collect()
+}
+
+fn bench_packing_algorithm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("packing_algorithm");
+    group.sample_size(10);
+    for count in [100, 1_000, 10_000, 100_000].iter() {
+        let items = items(*count);
+        group.bench_with_input(BenchmarkId::from_parameter(count), &items, |b, items| {
+            b.iter(|| packing_algorithm(Bin::new([10, 12, 14]), items))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_packing_algorithm);
+criterion_main!(benches);
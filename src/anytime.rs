@@ -0,0 +1,180 @@
+use crate::bin::Bin;
+use crate::error::Result;
+use crate::item::Item;
+use crate::packing_algorithm::pack_bins_with_strategy;
+use crate::strategy::{Objective, Strategy};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/**
+A token that can be shared with another thread, and used to ask a running solver to stop.
+
+```rust
+    use bin_packer_3d::anytime::CancellationToken;
+    let token = CancellationToken::new();
+    let handle = token.clone();
+    handle.cancel();
+    assert!(token.is_cancelled());
+```
+**/
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new token that hasn't been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks every solver holding a clone of this token to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether or not the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/**
+Represents how long a solver is allowed to run for. A budget without a deadline or cancellation
+token is unlimited.
+
+```rust
+    use bin_packer_3d::anytime::{Budget, CancellationToken};
+    use std::time::Duration;
+    let token = CancellationToken::new();
+    let budget = Budget::default()
+        .with_time_limit(Duration::from_millis(50))
+        .with_cancellation(token.clone());
+    assert!(!budget.is_exhausted());
+    token.cancel();
+    assert!(budget.is_exhausted());
+```
+**/
+#[derive(Clone, Debug, Default)]
+pub struct Budget {
+    deadline: Option<Instant>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl Budget {
+    /// Stops the solver once the deadline has passed.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stops the solver once the time limit has passed, starting from now.
+    pub fn with_time_limit(self, time_limit: Duration) -> Self {
+        self.with_deadline(Instant::now() + time_limit)
+    }
+
+    /// Stops the solver once the token has been cancelled.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Returns whether or not the solver should stop.
+    pub fn is_exhausted(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+            || self
+                .cancellation
+                .as_ref()
+                .is_some_and(|token| token.is_cancelled())
+    }
+}
+
+/// Represents the best packing an anytime solver found within its budget.
+#[derive(Clone, Debug)]
+pub struct AnytimePacking<'a, T = ()> {
+    /// The strategy that produced the best packing.
+    pub strategy: Strategy,
+    /// The packed bins of the best packing.
+    pub bins: Vec<Bin<'a, T>>,
+    /// Whether or not the budget ran out before every strategy was tried.
+    pub stopped_early: bool,
+}
+
+/**
+Packs the items with each of the strategies in turn, until either every strategy has been tried or
+the budget runs out, and returns the best packing found under the objective.
+
+The default strategy, which gives the same packing as `packing_algorithm`, is always packed first,
+even if the budget has already run out, so there's always an answer to return. An error is only
+returned if the default strategy can't pack the items, and any later strategy that can't pack them
+is skipped.
+
+The budget is checked between strategies, and a packing that has started is never interrupted, so
+the solver can run past its deadline by up to the time it takes to pack the items once.
+
+```rust
+  use bin_packer_3d::anytime::{pack_anytime, Budget};
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+  use bin_packer_3d::strategy::{Objective, Strategy};
+  use std::time::Duration;
+
+  let deck = Item::new("deck", [2.0, 8.0, 12.0]);
+  let die = Item::new("die", [8.0, 8.0, 8.0]);
+  let items = vec![deck, deck, die, deck, deck];
+
+  let budget = Budget::default().with_time_limit(Duration::from_millis(50));
+  let packing = pack_anytime(
+      Bin::new([8.0, 8.0, 12.0]),
+      &items,
+      &Strategy::all(),
+      Objective::FewestBins,
+      &budget,
+  )
+  .unwrap();
+  assert_eq!(packing.bins.len(), 2);
+```
+**/
+pub fn pack_anytime<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    items: &[Item<'a, T>],
+    strategies: &[Strategy],
+    objective: Objective,
+    budget: &Budget,
+) -> Result<AnytimePacking<'a, T>> {
+    let first_strategy = Strategy::default();
+    let bins = pack_bins_with_strategy(bin.clone_as_empty_bin(), items, &first_strategy)?;
+    let mut best_score = objective.score(&bins);
+    let mut best = AnytimePacking {
+        strategy: first_strategy,
+        bins,
+        stopped_early: false,
+    };
+
+    for strategy in strategies
+        .iter()
+        .filter(|&strategy| *strategy != first_strategy)
+    {
+        if budget.is_exhausted() {
+            best.stopped_early = true;
+            break;
+        }
+        // The default strategy has already given an answer, so strategies that can't pack the items
+        // are skipped, just as they are in `best_packing`:
+
+        let bins = match pack_bins_with_strategy(bin.clone_as_empty_bin(), items, strategy) {
+            Ok(bins) => bins,
+            Err(_) => continue,
+        };
+        let score = objective.score(&bins);
+        if score < best_score {
+            best_score = score;
+            best.strategy = *strategy;
+            best.bins = bins;
+        }
+    }
+
+    Ok(best)
+}
@@ -0,0 +1,172 @@
+use crate::bin::Bin;
+use crate::item::Item;
+use crate::packing_algorithm::pack_bins;
+use crate::shipping::Cost;
+use std::cmp::Ordering::Equal;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Represents a box size that could be stocked, along with what it costs to ship one.
+#[derive(Clone, Debug)]
+pub struct BoxSize<'a, T = ()> {
+    /// An empty bin with the box's dimensions and settings.
+    pub bin: Bin<'a, T>,
+    /// The cost of shipping one of the boxes.
+    pub cost: Cost,
+}
+
+impl<'a, T> BoxSize<'a, T> {
+    /// Creates a box size whose cost is its outer volume, so that choosing the cheapest sizes
+    /// minimises the total volume shipped.
+    pub fn new(bin: Bin<'a, T>) -> Self {
+        let cost = Cost::Fixed(bin.outer_dims().iter().product());
+        Self { bin, cost }
+    }
+
+    /// Sets the cost of shipping one of the boxes, which can be a fixed cost or a carrier's rule
+    /// that each packed box is billed by.
+    pub fn with_cost<C: Into<Cost>>(mut self, cost: C) -> Self {
+        self.cost = cost.into();
+        self
+    }
+}
+
+/// Represents the box sizes chosen from the candidates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Assortment {
+    /// The indexes of the chosen box sizes in the candidates, in ascending order.
+    pub chosen: Vec<usize>,
+    /// The total cost of shipping every order that the chosen sizes can hold.
+    pub total_cost: f64,
+    /// The indexes of the orders that none of the chosen sizes can hold.
+    pub unserved_orders: Vec<usize>,
+}
+
+/**
+Chooses up to `count` of the candidate box sizes to stock, so that shipping every order in the
+history costs as little as possible.
+
+Each order is packed by `pack_bins` into each candidate box size, and ships in whichever of the
+chosen sizes is cheapest for it, using as many boxes of that size as it needs. Orders that don't
+fit into any of the chosen sizes are avoided first, and then the total cost is minimised.
+
+The sizes are chosen greedily, one at a time, and then improved by swapping chosen sizes for
+others until no single swap lowers the cost. With the `rayon` feature enabled, the orders are
+packed in parallel.
+
+```rust
+  use bin_packer_3d::assortment::{choose_assortment, BoxSize};
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+
+  let book = Item::new("book", [2, 15, 23]);
+  let lamp = Item::new("lamp", [20, 20, 40]);
+  let orders = vec![vec![book], vec![book, book], vec![lamp]];
+  let candidates = vec![
+      BoxSize::new(Bin::new([5, 16, 24])),
+      BoxSize::new(Bin::new([21, 21, 41])),
+      BoxSize::new(Bin::new([30, 30, 50])),
+  ];
+
+  let assortment = choose_assortment(&orders, &candidates, 2);
+  assert_eq!(assortment.chosen, vec![0, 1]);
+  assert!(assortment.unserved_orders.is_empty());
+```
+**/
+pub fn choose_assortment<'a, T: Clone + Send + Sync>(
+    orders: &[Vec<Item<'a, T>>],
+    candidates: &[BoxSize<'a, T>],
+    count: usize,
+) -> Assortment {
+    // Work out what each order costs to ship in each of the candidate sizes:
+
+    let order_costs = |order: &Vec<Item<'a, T>>| {
+        candidates
+            .iter()
+            .map(|candidate| {
+                pack_bins(candidate.bin.clone_as_empty_bin(), order)
+                    .ok()
+                    .map(|bins| bins.iter().map(|bin| candidate.cost.of(bin)).sum())
+            })
+            .collect::<Vec<Option<f64>>>()
+    };
+
+    #[cfg(feature = "rayon")]
+    let costs = orders.par_iter().map(order_costs).collect::<Vec<_>>();
+    #[cfg(not(feature = "rayon"))]
+    let costs = orders.iter().map(order_costs).collect::<Vec<_>>();
+
+    // Choose sizes greedily, then swap them for unchosen sizes while that lowers the cost:
+
+    let count = count.min(candidates.len());
+    let mut chosen: Vec<usize> = Vec::with_capacity(count);
+    while chosen.len() < count {
+        let next = (0..candidates.len())
+            .filter(|candidate| !chosen.contains(candidate))
+            .map(|candidate| {
+                let mut with_candidate = chosen.clone();
+                with_candidate.push(candidate);
+                (score(&costs, &with_candidate), candidate)
+            })
+            .min_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Equal))
+            .map(|(_, candidate)| candidate)
+            .expect("Invalid state - there are more candidates than chosen sizes.");
+        chosen.push(next);
+    }
+
+    let mut best_score = score(&costs, &chosen);
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for position in 0..chosen.len() {
+            for candidate in 0..candidates.len() {
+                if chosen.contains(&candidate) {
+                    continue;
+                }
+                let mut swapped = chosen.clone();
+                swapped[position] = candidate;
+                let swapped_score = score(&costs, &swapped);
+                if swapped_score < best_score {
+                    best_score = swapped_score;
+                    chosen = swapped;
+                    improved = true;
+                }
+            }
+        }
+    }
+
+    chosen.sort_unstable();
+    let unserved_orders = costs
+        .iter()
+        .enumerate()
+        .filter(|(_, order_costs)| cheapest(order_costs, &chosen).is_none())
+        .map(|(order, _)| order)
+        .collect();
+    Assortment {
+        chosen,
+        total_cost: best_score.1,
+        unserved_orders,
+    }
+}
+
+/// Returns the number of orders that none of the chosen sizes can hold, and the total cost of
+/// shipping the rest.
+fn score(costs: &[Vec<Option<f64>>], chosen: &[usize]) -> (usize, f64) {
+    costs
+        .iter()
+        .fold((0, 0.0), |(unserved, total), order_costs| {
+            match cheapest(order_costs, chosen) {
+                Some(cost) => (unserved, total + cost),
+                None => (unserved + 1, total),
+            }
+        })
+}
+
+/// Returns the cost of shipping the order in whichever of the chosen sizes is cheapest for it.
+fn cheapest(order_costs: &[Option<f64>], chosen: &[usize]) -> Option<f64> {
+    chosen
+        .iter()
+        .filter_map(|&candidate| order_costs[candidate])
+        .min_by(|a, b| a.partial_cmp(b).unwrap_or(Equal))
+}
@@ -0,0 +1,155 @@
+use crate::bin::Bin;
+use crate::error::{Error, Result};
+use crate::grouping::kits;
+use crate::item::Item;
+use crate::packing_algorithm::{check_all_items_fit, check_carrier_rules, sort_for_loading, units};
+use crate::strategy::{Order, SortKey};
+use std::cmp::Ordering::Equal;
+
+/// Represents how full a bin is considered to be, when items are spread evenly across bins.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FillMeasure {
+    /// The total weight of the items in the bin.
+    Weight,
+
+    /// The total volume of the items in the bin.
+    Volume,
+}
+
+impl FillMeasure {
+    /**
+    Returns how full the bin is, under this measure.
+
+    ```rust
+        use bin_packer_3d::balance::FillMeasure;
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([4.0, 4.0, 4.0]);
+        bin.try_packing(Item::new("item", [2.0, 2.0, 2.0]).with_weight(3.0));
+        assert_eq!(FillMeasure::Weight.fill(&bin), 3.0);
+        assert_eq!(FillMeasure::Volume.fill(&bin), 8.0);
+    ```
+    **/
+    pub fn fill<T>(&self, bin: &Bin<'_, T>) -> f64 {
+        self.total(&bin.items)
+    }
+
+    fn total<T>(&self, items: &[Item<'_, T>]) -> f64 {
+        items.iter().map(|item| self.of(item)).sum()
+    }
+
+    pub(crate) fn of<T>(&self, item: &Item<'_, T>) -> f64 {
+        match self {
+            FillMeasure::Weight => item.weight,
+            FillMeasure::Volume => item.block.volume(),
+        }
+    }
+
+    /// Returns the other measure, which is used to break ties.
+    fn other(&self) -> Self {
+        match self {
+            FillMeasure::Weight => FillMeasure::Volume,
+            FillMeasure::Volume => FillMeasure::Weight,
+        }
+    }
+}
+
+/**
+Splits the items across exactly `count` bins like the given bin, spreading them as evenly as
+possible so that the fullest bin, under the measure, is as empty as possible. Every bin is
+returned, even if it's empty.
+
+Each item is packed into the emptiest bin that it fits into, starting with the heaviest or
+largest items, which is the Longest Processing Time rule from scheduling. A few different orders
+are tried, and the packing whose fullest bin is the emptiest is kept.
+
+```rust
+  use bin_packer_3d::balance::{pack_balanced, FillMeasure};
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+
+  let item = Item::new("item", [1.0, 1.0, 1.0]).with_weight(1.0);
+  let bins = pack_balanced(Bin::new([4.0, 4.0, 4.0]), &[item.with_quantity(6)], 3, FillMeasure::Weight)
+      .unwrap();
+  let weights = bins.iter().map(|bin| bin.total_weight()).collect::<Vec<f64>>();
+  assert_eq!(weights, vec![2.0, 2.0, 2.0]);
+```
+
+If the items can't be packed into that many bins, then a `NotEnoughBins` error is returned.
+
+```rust
+  use bin_packer_3d::balance::{pack_balanced, FillMeasure};
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::error::Error;
+  use bin_packer_3d::item::Item;
+
+  let item = Item::new("item", [4.0, 4.0, 4.0]);
+  let bins = pack_balanced(Bin::new([4.0, 4.0, 4.0]), &[item.with_quantity(3)], 2, FillMeasure::Volume);
+  assert!(matches!(bins, Err(Error::NotEnoughBins(_))));
+```
+**/
+pub fn pack_balanced<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    items: &[Item<'a, T>],
+    count: usize,
+    measure: FillMeasure,
+) -> Result<Vec<Bin<'a, T>>> {
+    check_all_items_fit(&bin, items)?;
+    check_carrier_rules(&bin, items)?;
+
+    let units = units(items);
+
+    let orders = [
+        Order::Fill(measure),
+        Order::Size(SortKey::LongestSide),
+        Order::Size(SortKey::Volume),
+        Order::Size(SortKey::LargestFace),
+    ];
+
+    let mut best: Option<(f64, Vec<Bin<'a, T>>)> = None;
+    for order in orders.iter() {
+        let mut sorted = units.clone();
+        sort_for_loading(&bin, &mut sorted, |a, b| order.compare(a, b));
+        if let Some(bins) = pack_emptiest_first(&bin, sorted, count, measure) {
+            let fullest = bins.iter().map(|bin| measure.fill(bin)).fold(0.0, f64::max);
+            if best.as_ref().is_none_or(|(best, _)| fullest < *best) {
+                best = Some((fullest, bins));
+            }
+        }
+    }
+
+    match best {
+        Some((_, bins)) => Ok(bins),
+        None => Err(Error::NotEnoughBins(format!(
+            "The items can't be packed into {} bins.",
+            count
+        ))),
+    }
+}
+
+/// Packs each of the sorted items into the emptiest of the bins that it fits into, or returns
+/// `None` if one of them doesn't fit into any of the bins. Items that must share a bin are packed
+/// together.
+fn pack_emptiest_first<'a, T: Clone>(
+    bin: &Bin<'a, T>,
+    sorted: Vec<Item<'a, T>>,
+    count: usize,
+    measure: FillMeasure,
+) -> Option<Vec<Bin<'a, T>>> {
+    let mut bins = (0..count)
+        .map(|_| bin.clone_as_empty_bin())
+        .collect::<Vec<Bin<'a, T>>>();
+    let mut fills = vec![(0.0, 0.0); count];
+    let mut by_fill = (0..count).collect::<Vec<usize>>();
+
+    for kit in kits(bin.groupings(), sorted) {
+        by_fill.sort_by(|&a, &b| fills[a].partial_cmp(&fills[b]).unwrap_or(Equal));
+        let bin_index = *by_fill
+            .iter()
+            .find(|&&index| bins[index].try_packing_kit(&kit))?;
+        fills[bin_index].0 += measure.total(&kit);
+        fills[bin_index].1 += measure.other().total(&kit);
+    }
+
+    Some(bins)
+}
@@ -1,29 +1,503 @@
-use crate::block::{Block, Dimension};
-use crate::item::Item;
+use crate::block::{Block, Dimension, Space};
+use crate::compartment::Compartment;
+use crate::grouping::Grouping;
+use crate::hazmat::{Segregation, SegregationTable};
+use crate::item::{Item, Weight};
+use crate::placement::{Placement, EPSILON, VERTICAL_AXIS};
+use crate::shipping::CarrierRule;
+use std::cmp::Ordering::Equal;
 
 /// Represents an bin that a user can insert items into.
+///
+/// The bin is generic over the payload of the items it holds, which defaults to `()`. Use
+/// `for_payload` to hold items with any other type of payload.
 /// ```rust
 /// use bin_packer_3d::bin::Bin;
 /// let bin = Bin::new([1.0, 2.0, 3.0]);
 /// ```
 
 #[derive(Clone, Debug)]
-pub struct Bin<'a> {
-    /// Represents the cuboid of this bin.
-    blocks: Vec<Block>,
+pub struct Bin<'a, T = ()> {
+    /// The inner length, width and height of this bin, which items are packed into.
+    dims: [Dimension; 3],
+    /// The outer length, width and height of this bin, including its walls.
+    outer_dims: [Dimension; 3],
+    /// Represents the remaining space inside of this bin.
+    blocks: Vec<Space>,
     /// Represents the items that are currently packed inside this bin.
-    pub items: Vec<Item<'a>>,
+    pub items: Vec<Item<'a, T>>,
+    /// Represents where each of the packed items has been placed, in the same order as `items`.
+    pub placements: Vec<Placement>,
+    /// How far apart two dimensions can be while still being considered equal.
+    tolerance: Dimension,
+    /// Whether or not any of the packed items limits what can be stacked on top of it.
+    has_load_limits: bool,
+    /// The smallest fraction of each item's base that must rest on the floor or on other items.
+    min_support: f64,
+    /// Whether or not items are placed to keep the centre of gravity near the centre of the bin.
+    balancing: bool,
+    /// The axis the bin is loaded and unloaded along, through a door at the far end of the axis.
+    loading_axis: Option<usize>,
+    /// The space kept clear between items that are next to each other.
+    gap: Dimension,
+    /// The space kept clear between items and the sides of the bin.
+    wall_clearance: Dimension,
+    /// The carrier's limits on the bin, which it must meet once it's packed.
+    carrier_rules: Vec<CarrierRule>,
+    /// The constraints on which items can share the bin.
+    groupings: Vec<Grouping<'a>>,
+    /// How far apart items of incompatible hazmat classes must be kept.
+    segregation: SegregationTable<'a>,
+    /// The sections the bin is split into by fixed dividers, if it's split up.
+    compartments: Vec<Compartment<'a>>,
+    /// The fixed cuboids inside the bin that items are packed around.
+    obstructions: Vec<Placement>,
+}
+
+/// Represents where an item can be packed into a bin.
+pub(crate) struct Fit {
+    /// The index of the bin's block that the item is packed into.
+    block_index: usize,
+    placement: Placement,
+    /// The blocks that remain around the item once it's packed.
+    remaining: Vec<Space>,
 }
 
 impl<'a> Bin<'a> {
-    /// Creates a new Bin from it's dimensions.
+    /// Creates a new Bin from it's inner length, width and height, where the height runs along the
+    /// vertical axis. Until its walls are set, the bin's outer dimensions are the same as its
+    /// inner dimensions.
     pub fn new<F: Into<Dimension> + Copy>(dims: [F; 3]) -> Self {
+        let dims = [dims[0].into(), dims[1].into(), dims[2].into()];
         Self {
-            blocks: vec![Block::new(dims[0], dims[1], dims[2])],
+            dims,
+            outer_dims: dims,
+            blocks: vec![Space::from_lengths(dims)],
             items: vec![],
+            placements: vec![],
+            tolerance: 0 as Dimension,
+            has_load_limits: false,
+            min_support: 0.0,
+            balancing: false,
+            loading_axis: None,
+            gap: 0 as Dimension,
+            wall_clearance: 0 as Dimension,
+            carrier_rules: vec![],
+            groupings: vec![],
+            segregation: SegregationTable::default(),
+            compartments: vec![],
+            obstructions: vec![],
         }
     }
 
+    /**
+    Converts an empty bin into one that holds items with the given type of payload, keeping all of
+    its settings.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([2.0, 2.0, 2.0]).for_payload::<&str>();
+        assert!(bin.try_packing(Item::new("item1", [1.0, 1.0, 1.0]).with_data("SKU-1")).is_some());
+        assert_eq!(bin.items[0].data, "SKU-1");
+    ```
+    **/
+    pub fn for_payload<T>(self) -> Bin<'a, T> {
+        self.clone_as_empty_bin_for()
+    }
+}
+
+impl<'a, T> Bin<'a, T> {
+    /// Returns the inner length, width and height of the bin, which items are packed into.
+    pub fn dims(&self) -> [Dimension; 3] {
+        self.dims
+    }
+
+    /// Returns the outer length, width and height of the bin, including its walls.
+    pub fn outer_dims(&self) -> [Dimension; 3] {
+        self.outer_dims
+    }
+
+    /**
+    Sets the thickness of the bin's walls, floor and lid, so that its outer dimensions are its inner
+    dimensions plus a wall on either side.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        let bin = Bin::new([10.0, 8.0, 6.0]).with_wall_thickness(0.5);
+        assert_eq!(bin.dims(), [10.0, 8.0, 6.0]);
+        assert_eq!(bin.outer_dims(), [11.0, 9.0, 7.0]);
+    ```
+    **/
+    pub fn with_wall_thickness(mut self, wall_thickness: Dimension) -> Self {
+        let thickness = 2.0 * wall_thickness;
+        self.outer_dims = [
+            self.dims[0] + thickness,
+            self.dims[1] + thickness,
+            self.dims[2] + thickness,
+        ];
+        self
+    }
+
+    /**
+    Sets the bin's outer length, width and height, for bins whose walls aren't the same thickness
+    all the way around.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        let bin = Bin::new([10.0, 8.0, 6.0]).with_outer_dims([10.5, 8.5, 7.0]);
+        assert_eq!(bin.outer_dims(), [10.5, 8.5, 7.0]);
+    ```
+    **/
+    pub fn with_outer_dims<F: Into<Dimension> + Copy>(mut self, outer_dims: [F; 3]) -> Self {
+        self.outer_dims = [
+            outer_dims[0].into(),
+            outer_dims[1].into(),
+            outer_dims[2].into(),
+        ];
+        self
+    }
+
+    /**
+    Sets how far apart two dimensions can be while still being considered equal when fitting items,
+    which helps with dimensions that can't be represented exactly.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let item = Item::new("item1", [0.1 + 0.2, 1.0, 1.0]);
+        let bin = Bin::new([0.3, 1.0, 1.0]).with_tolerance(1e-9);
+        assert!(bin.fits(&item));
+    ```
+    **/
+    pub fn with_tolerance(mut self, tolerance: Dimension) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /**
+    Requires at least the given fraction of each item's base to rest on the floor of the bin, or on
+    the top faces of other items, so that items aren't left floating.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([4.0, 4.0, 4.0]).with_min_support(0.75);
+        assert!(bin.try_packing(Item::new("item1", [4.0, 4.0, 2.0])).is_some());
+        assert!(bin.try_packing(Item::new("item2", [4.0, 4.0, 2.0])).is_some());
+        assert_eq!(bin.support_ratios(), vec![1.0, 1.0]);
+    ```
+    **/
+    pub fn with_min_support(mut self, min_support: f64) -> Self {
+        self.min_support = min_support;
+        self
+    }
+
+    /**
+    Places each item wherever it keeps the bin's centre of gravity closest to the centre of the
+    bin's floor, rather than in the first space that it fits into.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([6.0, 4.0, 1.0]).with_balancing();
+        bin.try_packing(Item::new("heavy", [2.0, 2.0, 1.0]).with_weight(5.0));
+        bin.try_packing(Item::new("heavy", [2.0, 2.0, 1.0]).with_weight(5.0));
+        bin.try_packing(Item::new("light", [2.0, 2.0, 1.0]).with_weight(1.0));
+        assert_eq!(bin.placements[2].origin, [4.0, 0.0, 0.0]);
+    ```
+    **/
+    pub fn with_balancing(mut self) -> Self {
+        self.balancing = true;
+        self
+    }
+
+    /**
+    Loads and unloads the bin through a door at the far end of the axis, where the axes are
+    numbered in the same order as the bin's length, width and height. Items for later delivery
+    stops are packed first, deeper into the bin, and items are never placed where they'd block
+    access to an item for an earlier stop. The axis must be 0, 1 or 2, otherwise packing the bin
+    returns an `InvalidAxis` error, and `try_packing` ignores delivery stops.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        use bin_packer_3d::packing_algorithm::pack_bins;
+        let first = Item::new("first", [2.0, 2.0, 2.0]).with_delivery_stop(1);
+        let last = Item::new("last", [2.0, 2.0, 2.0]).with_delivery_stop(2);
+        let bins = pack_bins(Bin::new([4.0, 2.0, 2.0]).with_loading_axis(0), &[first, last]).unwrap();
+        assert_eq!(bins[0].items[0].id, "last");
+        assert_eq!(bins[0].placements[1].origin, [2.0, 0.0, 0.0]);
+    ```
+    **/
+    pub fn with_loading_axis(mut self, loading_axis: usize) -> Self {
+        self.loading_axis = Some(loading_axis);
+        self
+    }
+
+    /**
+    Keeps the given amount of space clear between items that are next to each other, such as room
+    for a packer's fingers. Items that are stacked still rest directly on each other. This should
+    be set before any items are packed.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([5.0, 2.0, 2.0]).with_gap(1.0);
+        assert!(bin.try_packing(Item::new("item1", [2.0, 2.0, 2.0])).is_some());
+        assert!(bin.try_packing(Item::new("item2", [2.0, 2.0, 2.0])).is_some());
+        assert_eq!(bin.placements[1].origin, [3.0, 0.0, 0.0]);
+    ```
+    **/
+    pub fn with_gap(mut self, gap: Dimension) -> Self {
+        self.gap = gap;
+        self.blocks = self.empty_spaces();
+        self
+    }
+
+    /**
+    Keeps the given amount of space clear between items and the sides of the bin. Items still
+    rest directly on the floor. This should be set before any items are packed.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([4.0, 4.0, 2.0]).with_wall_clearance(1.0);
+        assert!(bin.try_packing(Item::new("item1", [2.0, 2.0, 2.0])).is_some());
+        assert_eq!(bin.placements[0].origin, [1.0, 1.0, 0.0]);
+        assert!(!bin.fits(&Item::new("item2", [3.0, 2.0, 2.0])));
+    ```
+    **/
+    pub fn with_wall_clearance(mut self, wall_clearance: Dimension) -> Self {
+        self.wall_clearance = wall_clearance;
+        self.blocks = self.empty_spaces();
+        self
+    }
+
+    /**
+    Attaches a carrier's limits to the bin. Packing into a bin that's too large for the carrier
+    raises an error naming the rule it breaks, and items are never packed into a bin in a way that
+    would take it over the carrier's weight limit.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        use bin_packer_3d::shipping::CarrierRule;
+        let mut bin = Bin::new([10.0, 10.0, 10.0])
+            .with_carrier_rules(&[CarrierRule::MaxWeight(20.0), CarrierRule::MaxSide(48.0)]);
+        assert!(bin.try_packing(Item::new("item1", [5.0, 5.0, 5.0]).with_weight(15.0)).is_some());
+        assert!(bin.try_packing(Item::new("item2", [5.0, 5.0, 5.0]).with_weight(15.0)).is_none());
+    ```
+    **/
+    pub fn with_carrier_rules(mut self, carrier_rules: &[CarrierRule]) -> Self {
+        self.carrier_rules = carrier_rules.to_vec();
+        self
+    }
+
+    /// Returns the carrier's limits that are attached to the bin.
+    pub fn carrier_rules(&self) -> &[CarrierRule] {
+        &self.carrier_rules
+    }
+
+    /// Returns the first of the carrier rules attached to the bin that it breaks, as it's currently
+    /// packed.
+    pub fn broken_carrier_rule(&self) -> Option<CarrierRule> {
+        self.carrier_rules
+            .iter()
+            .find(|rule| !rule.is_met_by(self))
+            .copied()
+    }
+
+    /**
+    Attaches constraints on which items can share the bin, based on the items' tags. Items are
+    never packed alongside items they must be kept apart from, and the packing algorithm keeps the
+    items of each `SameBin` group together in one bin.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::grouping::Grouping;
+        use bin_packer_3d::item::Item;
+        use bin_packer_3d::packing_algorithm::pack_bins;
+        let lamp = Item::new("lamp", [2.0, 2.0, 2.0]).with_tags(&["kit"]);
+        let bulb = Item::new("bulb", [1.0, 1.0, 1.0]).with_tags(&["kit"]);
+        let vase = Item::new("vase", [2.0, 2.0, 3.0]);
+        let bin = Bin::new([2.0, 2.0, 4.0]).with_groupings(&[Grouping::SameBin("kit")]);
+        let bins = pack_bins(bin, &[vase, lamp, bulb]).unwrap();
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[1].items.len(), 2);
+    ```
+    **/
+    pub fn with_groupings(mut self, groupings: &[Grouping<'a>]) -> Self {
+        self.groupings = groupings.to_vec();
+        self
+    }
+
+    /// Returns the constraints on which items can share the bin.
+    pub fn groupings(&self) -> &[Grouping<'a>] {
+        &self.groupings
+    }
+
+    /// Returns whether or not the item must be kept apart from any of the items in the bin.
+    fn is_separated_from(&self, item: &Item<'a, T>) -> bool {
+        self.groupings.iter().any(|grouping| {
+            self.items
+                .iter()
+                .any(|packed| grouping.separates(item, packed))
+        }) || (item.hazmat_class.is_some()
+            && !self.segregation.is_empty()
+            && self.items.iter().any(|packed| {
+                self.segregation.between(item, packed) == Some(Segregation::DifferentBin)
+            }))
+    }
+
+    /**
+    Splits the bin into compartments with fixed dividers between them, such as the frozen, chilled
+    and ambient sections of a grocery tote. Each compartment is filled separately, and items that
+    require a zone are only packed into compartments of that zone. The bin's wall clearance still
+    applies, and so does its gap between items in the same compartment, but items can be packed
+    against either side of a divider. It's still a single bin once it's packed. This should be set
+    before any items are packed.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::compartment::Compartment;
+        use bin_packer_3d::item::Item;
+        let mut tote = Bin::new([4.0, 2.0, 2.0]).with_compartments(&[
+            Compartment::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]),
+            Compartment::new([2.0, 0.0, 0.0], [2.0, 2.0, 2.0]),
+        ]);
+        // the long item would have to cross the divider
+        assert!(tote.try_packing(Item::new("long", [3.0, 2.0, 2.0])).is_none());
+        assert!(tote.try_packing(Item::new("item1", [2.0, 2.0, 2.0])).is_some());
+        assert!(tote.try_packing(Item::new("item2", [2.0, 2.0, 2.0])).is_some());
+        assert_eq!(tote.placements[1].origin, [2.0, 0.0, 0.0]);
+    ```
+    **/
+    pub fn with_compartments(mut self, compartments: &[Compartment<'a>]) -> Self {
+        self.compartments = compartments.to_vec();
+        self.blocks = self.empty_spaces();
+        self
+    }
+
+    /**
+    Declares fixed cuboids inside the bin, such as a van's wheel wells or an item that's already in
+    the bin, which are in place before packing begins. Items are packed around them, and can rest
+    on top of them. This should be set before any items are packed.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        use bin_packer_3d::placement::Placement;
+        let wheel_well = Placement { origin: [0.0, 0.0, 0.0], dims: [2.0, 2.0, 1.0] };
+        let mut van = Bin::new([4.0, 2.0, 2.0]).with_obstructions(&[wheel_well]);
+        assert!(van.try_packing(Item::new("item1", [2.0, 2.0, 2.0])).is_some());
+        assert!(van.try_packing(Item::new("item2", [2.0, 2.0, 1.0])).is_some());
+        assert_eq!(van.placements[0].origin, [2.0, 0.0, 0.0]);
+        assert_eq!(van.placements[1].origin, [0.0, 0.0, 1.0]);
+    ```
+    **/
+    pub fn with_obstructions(mut self, obstructions: &[Placement]) -> Self {
+        self.obstructions = obstructions.to_vec();
+        self.blocks = self.empty_spaces();
+        self
+    }
+
+    /// Returns the fixed cuboids inside the bin that items are packed around.
+    pub fn obstructions(&self) -> &[Placement] {
+        &self.obstructions
+    }
+
+    /// Returns the compartments the bin is split into, which is empty if it isn't split up.
+    pub fn compartments(&self) -> &[Compartment<'a>] {
+        &self.compartments
+    }
+
+    /**
+    Attaches a hazmat segregation table to the bin. Items are never packed into the bin alongside
+    items of a class they must be kept out of the same bin as, or closer than the minimum distance
+    to items of a class they must be kept away from.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::hazmat::{Segregation, SegregationTable};
+        use bin_packer_3d::item::Item;
+        let table = SegregationTable::default().with_rule("3", "8", Segregation::MinDistance(2.0));
+        let fuel = Item::new("fuel", [1.0, 1.0, 1.0]).with_hazmat_class("3");
+        let acid = Item::new("acid", [1.0, 1.0, 1.0]).with_hazmat_class("8");
+        let filler = Item::new("filler", [1.0, 1.0, 1.0]);
+        let mut bin = Bin::new([4.0, 1.0, 1.0]).with_segregation(table);
+        assert!(bin.try_packing(fuel).is_some());
+        assert!(bin.try_packing(acid).is_none());
+        bin.try_packing(filler);
+        bin.try_packing(filler);
+        assert!(bin.try_packing(acid).is_some());
+        assert_eq!(bin.placements[3].origin, [3.0, 0.0, 0.0]);
+    ```
+    **/
+    pub fn with_segregation(mut self, segregation: SegregationTable<'a>) -> Self {
+        self.segregation = segregation;
+        self
+    }
+
+    /// Returns the hazmat segregation table attached to the bin.
+    pub fn segregation(&self) -> &SegregationTable<'a> {
+        &self.segregation
+    }
+
+    /// Returns whether or not the item, once placed, would be too close to any of the items in the
+    /// bin under the segregation table.
+    fn is_too_close(&self, item: &Item<'a, T>, placement: &Placement) -> bool {
+        item.hazmat_class.is_some()
+            && !self.segregation.is_empty()
+            && self
+                .items
+                .iter()
+                .zip(self.placements.iter())
+                .any(|(packed, packed_placement)| {
+                    !self.segregation.is_met_by(
+                        (item, placement),
+                        (packed, packed_placement),
+                        self.tolerance,
+                    )
+                })
+    }
+
+    /// Returns the most weight the bin can hold under its carrier rules, if it's limited.
+    fn max_weight(&self) -> Option<Weight> {
+        self.carrier_rules
+            .iter()
+            .filter_map(|rule| match rule {
+                CarrierRule::MaxWeight(max_weight) => Some(*max_weight),
+                _ => None,
+            })
+            .reduce(Weight::min)
+    }
+
+    /// Returns the space kept clear between items that are next to each other.
+    pub fn gap(&self) -> Dimension {
+        self.gap
+    }
+
+    /// Returns the space kept clear between items and the sides of the bin.
+    pub fn wall_clearance(&self) -> Dimension {
+        self.wall_clearance
+    }
+
+    /// Returns the axis the bin is loaded and unloaded along, if it has one.
+    pub fn loading_axis(&self) -> Option<usize> {
+        self.loading_axis
+    }
+
+    /// Returns how far apart two dimensions can be while still being considered equal.
+    pub fn tolerance(&self) -> Dimension {
+        self.tolerance
+    }
+
+    /// Returns the smallest fraction of each item's base that must be supported.
+    pub fn min_support(&self) -> f64 {
+        self.min_support
+    }
+
     /**
     Returns whether or not the Bin's dimensions can emcompass or match the item.
 
@@ -44,10 +518,17 @@ impl<'a> Bin<'a> {
         assert!(!bin.fits(&item));
     ```
     **/
-    pub fn fits(&self, item: &Item<'_>) -> bool {
-        self.blocks
-            .iter()
-            .any(|block| block.does_it_fit(&item.block))
+    pub fn fits(&self, item: &Item<'a, T>) -> bool {
+        if item.zone.is_none() {
+            return self.fits_block(&item.padded_block());
+        }
+        let cell = self.cell(item);
+        self.blocks.iter().any(|remaining| {
+            self.allows(item, remaining)
+                && remaining
+                    .block
+                    .does_it_fit_with_tolerance(&cell, self.tolerance)
+        })
     }
 
     /**
@@ -74,27 +555,469 @@ impl<'a> Bin<'a> {
         );
     ```
     **/
-    pub fn try_packing(&mut self, item: Item<'a>) -> Option<()> {
-        let block_to_pack_index =
-            self.blocks
-                .iter()
-                .enumerate()
-                .find_map(|(block_index, block)| {
-                    if block.does_it_fit(&item.block) {
-                        Some(block_index)
-                    } else {
-                        None
-                    }
-                })?;
-        let block_to_pack = self.blocks.remove(block_to_pack_index);
-        self.blocks.append(
-            &mut block_to_pack
-                .best_fit(&item.block)
-                .expect("Invalid state - the block doesn't fit the item."),
+    pub fn try_packing(&mut self, item: Item<'a, T>) -> Option<()> {
+        let fit = self.find_fit(&item)?;
+        self.pack_fit(fit, item);
+        Some(())
+    }
+
+    /// Packs every one of the items into the bin, or none of them if they don't all fit, and
+    /// returns whether or not they were packed.
+    pub(crate) fn try_packing_kit(&mut self, kit: &[Item<'a, T>]) -> bool
+    where
+        T: Clone,
+    {
+        if let [item] = kit {
+            return self.try_packing(item.clone()).is_some();
+        }
+        let mut packed = self.clone();
+        for item in kit {
+            if packed.try_packing(item.clone()).is_none() {
+                return false;
+            }
+        }
+        *self = packed;
+        true
+    }
+
+    /// Finds the first of the bin's remaining blocks that the item can be packed into, without
+    /// overloading any of the items below it.
+    pub(crate) fn find_fit(&self, item: &Item<'a, T>) -> Option<Fit> {
+        let check_loads = self.has_load_limits || item.has_load_limits();
+        if item.weight > 0 as Weight {
+            if let Some(max_weight) = self.max_weight() {
+                if self.total_weight() + item.weight > max_weight + EPSILON {
+                    return None;
+                }
+            }
+        }
+        if self.is_separated_from(item) {
+            return None;
+        }
+
+        let cell = self.cell(item);
+        let mut fits = self
+            .blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| self.allows(item, block))
+            .filter_map(|(block_index, block)| {
+                let (item_space, remaining) = block.best_fit(&cell, self.tolerance)?;
+                let placement = self.placement_in(item, &item_space);
+                let remaining = self.lower_spaces_above(&item_space, remaining);
+
+                let padded = placement.padded(item.padding);
+                if self.min_support > 0.0
+                    && self.support_ratio(&padded) + EPSILON < self.min_support
+                {
+                    return None;
+                }
+                if check_loads && !self.can_bear(item, &padded) {
+                    return None;
+                }
+                if self.blocks_delivery(item, &padded) {
+                    return None;
+                }
+                if self.is_too_close(item, &placement) {
+                    return None;
+                }
+                Some(Fit {
+                    block_index,
+                    placement,
+                    remaining,
+                })
+            });
+
+        if self.balancing && item.weight > 0 as Weight {
+            fits.map(|fit| (self.cog_offset_with(item, &fit.placement), fit))
+                .min_by(|(offset_a, _), (offset_b, _)| {
+                    offset_a.partial_cmp(offset_b).unwrap_or(Equal)
+                })
+                .map(|(_, fit)| fit)
+        } else {
+            fits.next()
+        }
+    }
+
+    /// Returns the space the item needs when it's fitted into one of the bin's blocks, including its
+    /// padding and the gap that's kept beside it.
+    fn cell(&self, item: &Item<'a, T>) -> Block {
+        let [d1, d2, d3] = item.padded_block().dims;
+        Block::new(d1 + self.gap, d2 + self.gap, d3 + self.gap)
+    }
+
+    /// Returns where the item is placed when it's fitted into the cell.
+    fn placement_in(&self, item: &Item<'a, T>, cell: &Space) -> Placement {
+        let lengths = cell.lengths();
+        let margin = 2.0 * item.padding + self.gap;
+        Placement {
+            origin: [
+                cell.origin[0] + item.padding,
+                cell.origin[1] + item.padding,
+                cell.origin[2] + item.padding,
+            ],
+            dims: [
+                lengths[0] - margin,
+                lengths[1] - margin,
+                lengths[2] - margin,
+            ],
+        }
+    }
+
+    /// Returns the same bin with a different length along the axis, without any items.
+    pub(crate) fn with_length(&self, axis: usize, length: Dimension) -> Self {
+        let mut bin = self.clone_as_empty_bin();
+        bin.outer_dims[axis] += length - bin.dims[axis];
+        bin.dims[axis] = length;
+        bin.blocks = bin.empty_spaces();
+        bin
+    }
+
+    /// Returns the spaces that items can be fitted into when the bin is empty, which is either the
+    /// whole bin or each of its compartments.
+    ///
+    /// Every cell includes the gap beside an item, so each block is one gap longer than the space
+    /// that's actually free, and items are fitted right up against the sides of the bin and the
+    /// top of the items below them. The blocks are widened by a gap along the bin's length and
+    /// width, and lengthened by a gap above the floor.
+    fn empty_spaces(&self) -> Vec<Space> {
+        let clear_area = Compartment::new(
+            [self.wall_clearance, self.wall_clearance, 0 as Dimension],
+            [
+                self.dims[0] - 2.0 * self.wall_clearance,
+                self.dims[1] - 2.0 * self.wall_clearance,
+                self.dims[2],
+            ],
         );
+        let mut spaces = if self.compartments.is_empty() {
+            vec![self.space_in(&clear_area, &clear_area)]
+        } else {
+            self.compartments
+                .iter()
+                .map(|compartment| self.space_in(compartment, &clear_area))
+                .collect()
+        };
+
+        // Items keep a gap from the sides of each obstruction, just as they do from each other, and
+        // rest directly on top of it:
+
+        for obstruction in self.obstructions.iter() {
+            let lengths = [
+                obstruction.dims[0] + self.gap,
+                obstruction.dims[1] + self.gap,
+                obstruction.dims[2],
+            ];
+            spaces = spaces
+                .iter()
+                .flat_map(|space| space.without(obstruction.origin, lengths))
+                .collect();
+        }
+        spaces
+    }
+
+    /// Returns the block covering the part of the compartment that's inside of the clear area,
+    /// including the gap beside it.
+    fn space_in(&self, compartment: &Compartment<'a>, clear_area: &Compartment<'a>) -> Space {
+        let mut origin = [0 as Dimension; 3];
+        let mut lengths = [0 as Dimension; 3];
+        for axis in 0..3 {
+            origin[axis] = compartment.origin[axis].max(clear_area.origin[axis]);
+            let end = (compartment.origin[axis] + compartment.dims[axis])
+                .min(clear_area.origin[axis] + clear_area.dims[axis]);
+            lengths[axis] = (end - origin[axis]).max(0 as Dimension) + self.gap;
+        }
+        Space::at(origin, lengths)
+    }
+
+    /// Returns whether or not the item can be packed into the space, based on the zone of the
+    /// compartment the space is in.
+    fn allows(&self, item: &Item<'a, T>, space: &Space) -> bool {
+        item.zone.is_none()
+            || self.compartments.iter().any(|compartment| {
+                compartment.accepts(item.zone) && compartment.contains_point(space.origin)
+            })
+    }
+
+    /// Moves the blocks above the cell down onto the top of the item's padding, so that the items
+    /// packed into them rest on the item rather than on the gap above it.
+    fn lower_spaces_above(&self, cell: &Space, remaining: Vec<Space>) -> Vec<Space> {
+        if self.gap <= 0 as Dimension {
+            return remaining;
+        }
+        remaining
+            .into_iter()
+            .map(|space| {
+                if space.origin[VERTICAL_AXIS] > cell.origin[VERTICAL_AXIS] {
+                    let mut origin = space.origin;
+                    let mut lengths = space.lengths();
+                    origin[VERTICAL_AXIS] -= self.gap;
+                    lengths[VERTICAL_AXIS] += self.gap;
+                    Space::at(origin, lengths)
+                } else {
+                    space
+                }
+            })
+            .collect()
+    }
+
+    /// Packs the item where it fits, replacing the block it's packed into with the blocks that
+    /// remain around the item.
+    pub(crate) fn pack_fit(&mut self, mut fit: Fit, item: Item<'a, T>) {
+        self.blocks.remove(fit.block_index);
+        self.blocks.append(&mut fit.remaining);
+        self.has_load_limits |= item.has_load_limits();
         self.items.push(item);
-        Some(())
+        self.placements.push(fit.placement);
+    }
+
+    /// Returns whether or not any of the bin's remaining blocks can emcompass or match the block,
+    /// once the gap beside it has been added.
+    pub(crate) fn fits_block(&self, block: &Block) -> bool {
+        let [d1, d2, d3] = block.dims;
+        let cell = Block::new(d1 + self.gap, d2 + self.gap, d3 + self.gap);
+        self.blocks.iter().any(|remaining| {
+            remaining
+                .block
+                .does_it_fit_with_tolerance(&cell, self.tolerance)
+        })
+    }
+
+    /**
+    Returns the space each of the packed items takes up once it's been padded, in the same order
+    as `items`. Items rest on each other's padding, so these are used when working out what each
+    item is supported by.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([4.0, 4.0, 4.0]);
+        bin.try_packing(Item::new("vase", [2.0, 2.0, 2.0]).with_padding(1.0));
+        assert_eq!(bin.placements[0].origin, [1.0, 1.0, 1.0]);
+        assert_eq!(bin.padded_placements()[0].origin, [0.0, 0.0, 0.0]);
+    ```
+    **/
+    pub fn padded_placements(&self) -> Vec<Placement> {
+        self.items
+            .iter()
+            .zip(self.placements.iter())
+            .map(|(item, placement)| placement.padded(item.padding))
+            .collect()
+    }
+
+    /**
+    Returns the total weight resting on top of each of the packed items, in the same order as
+    `items`. Each item's weight, along with the weight resting on it, is shared between the items
+    it rests directly on, in proportion to how much of its base each of them supports.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([4.0, 4.0, 6.0]);
+        for id in ["bottom", "middle", "top"].iter() {
+            bin.try_packing(Item::new(id, [4.0, 4.0, 2.0]).with_weight(3.0));
+        }
+        assert_eq!(bin.loads(), vec![6.0, 3.0, 0.0]);
+    ```
+    **/
+    pub fn loads(&self) -> Vec<Weight> {
+        let weights = self
+            .items
+            .iter()
+            .map(|item| item.weight)
+            .collect::<Vec<Weight>>();
+        loads(&weights, &self.padded_placements(), self.tolerance)
+    }
+
+    /// Returns the total weight of the packed items.
+    pub fn total_weight(&self) -> Weight {
+        self.items.iter().map(|item| item.weight).sum()
+    }
+
+    /**
+    Returns the bin's centre of gravity, based on the weights and placements of the packed items.
+    Returns None if the packed items don't weigh anything.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let mut bin = Bin::new([4.0, 4.0, 4.0]);
+        bin.try_packing(Item::new("item1", [4.0, 4.0, 2.0]).with_weight(3.0));
+        bin.try_packing(Item::new("item2", [4.0, 4.0, 2.0]).with_weight(1.0));
+        assert_eq!(bin.center_of_gravity(), Some([2.0, 2.0, 1.5]));
+    ```
+    **/
+    pub fn center_of_gravity(&self) -> Option<[Dimension; 3]> {
+        center_of_gravity(
+            self.items
+                .iter()
+                .zip(self.placements.iter())
+                .map(|(item, placement)| (item.weight, placement)),
+        )
+    }
+
+    /// Returns the horizontal distance between the bin's centre of gravity and the centre of the
+    /// bin's floor. Returns None if the packed items don't weigh anything.
+    pub fn cog_offset(&self) -> Option<Dimension> {
+        self.center_of_gravity()
+            .map(|center| self.horizontal_offset(&center))
+    }
+
+    /// Returns whether or not the bin's centre of gravity is within the given horizontal distance
+    /// of the centre of the bin's floor. A bin whose items don't weigh anything is balanced.
+    pub fn is_balanced(&self, max_offset: Dimension) -> bool {
+        self.cog_offset()
+            .is_none_or(|offset| offset <= max_offset + EPSILON)
+    }
+
+    /// Returns the horizontal distance between the centre of gravity and the centre of the bin's
+    /// floor once the item has been placed.
+    fn cog_offset_with(&self, item: &Item<'a, T>, placement: &Placement) -> Dimension {
+        center_of_gravity(
+            self.items
+                .iter()
+                .zip(self.placements.iter())
+                .map(|(packed, placement)| (packed.weight, placement))
+                .chain(std::iter::once((item.weight, placement))),
+        )
+        .map_or(0 as Dimension, |center| self.horizontal_offset(&center))
+    }
+
+    fn horizontal_offset(&self, point: &[Dimension; 3]) -> Dimension {
+        (0..VERTICAL_AXIS)
+            .map(|axis| (point[axis] - self.dims[axis] / 2.0).powi(2))
+            .sum::<Dimension>()
+            .sqrt()
+    }
+
+    /// Returns the fraction of each packed item's base that rests on the floor of the bin, or on
+    /// the top faces of other items, in the same order as `items`.
+    pub fn support_ratios(&self) -> Vec<f64> {
+        self.padded_placements()
+            .iter()
+            .map(|placement| self.support_ratio(placement))
+            .collect()
+    }
+
+    /// Returns the fraction of the padded placement's base that rests on the floor of the bin, on
+    /// the top faces of the packed items' padding, or on top of the bin's obstructions.
+    fn support_ratio(&self, placement: &Placement) -> f64 {
+        if placement.bottom() <= self.tolerance.max(EPSILON) {
+            return 1.0;
+        }
+        let supported_area = self
+            .padded_placements()
+            .iter()
+            .chain(self.obstructions.iter())
+            .filter(|below| placement.rests_on(below, self.tolerance))
+            .map(|below| placement.footprint_overlap(below))
+            .sum::<Dimension>();
+        (supported_area / placement.base_area()).min(1.0)
+    }
+
+    /// Returns whether or not placing the item's padded placement would block access to an item
+    /// for an earlier delivery stop, or leave the item blocked by an item for a later stop.
+    fn blocks_delivery(&self, item: &Item<'a, T>, placement: &Placement) -> bool {
+        let (loading_axis, stop) = match (self.loading_axis, item.delivery_stop) {
+            (Some(loading_axis), Some(stop)) if loading_axis < 3 => (loading_axis, stop),
+            _ => return false,
+        };
+        self.items
+            .iter()
+            .zip(self.placements.iter())
+            .any(|(packed, packed_placement)| {
+                let packed_placement = packed_placement.padded(packed.padding);
+                match packed.delivery_stop {
+                    Some(packed_stop) if packed_stop > stop => {
+                        packed_placement.blocks_unloading(placement, loading_axis, self.tolerance)
+                    }
+                    Some(packed_stop) if packed_stop < stop => {
+                        placement.blocks_unloading(&packed_placement, loading_axis, self.tolerance)
+                    }
+                    _ => false,
+                }
+            })
+    }
+
+    /// Returns the number of items stacked on top of each of the packed items, in the same order
+    /// as `items`.
+    pub fn stacked_counts(&self) -> Vec<usize> {
+        stacked(&self.padded_placements(), self.tolerance)
+    }
+
+    /// Returns whether or not the item's padded placement can be used without overloading any of
+    /// the items in the bin, including itself.
+    fn can_bear(&self, item: &Item<'a, T>, placement: &Placement) -> bool {
+        let mut placements = self.padded_placements();
+        placements.push(*placement);
+        let mut weights = self
+            .items
+            .iter()
+            .map(|packed| packed.weight)
+            .collect::<Vec<Weight>>();
+        weights.push(item.weight);
+        let limits = self
+            .items
+            .iter()
+            .chain(std::iter::once(item))
+            .map(|packed| (packed.max_load, packed.max_stack));
+
+        let loads = loads(&weights, &placements, self.tolerance);
+        let stacked = stacked(&placements, self.tolerance);
+        limits.zip(loads.iter().zip(stacked.iter())).all(
+            |((max_load, max_stack), (load, stacked))| {
+                max_load.is_none_or(|max_load| *load <= max_load + EPSILON)
+                    && max_stack.is_none_or(|max_stack| *stacked <= max_stack)
+            },
+        )
     }
+
+    /// Returns how far along the axis the packed items reach, including their padding and the
+    /// bin's wall clearance beyond them.
+    pub(crate) fn used_length(&self, axis: usize) -> Dimension {
+        let used = self
+            .padded_placements()
+            .iter()
+            .map(|placement| placement.origin[axis] + placement.dims[axis])
+            .fold(0 as Dimension, Dimension::max);
+        if axis == VERTICAL_AXIS || self.items.is_empty() {
+            used
+        } else {
+            used + self.wall_clearance
+        }
+    }
+
+    /// Changes the bin's length along the axis, keeping the items where they are. The remaining
+    /// blocks that reach the end of the bin are stretched or cut to the new end, and the bin's walls
+    /// stay the same thickness.
+    pub(crate) fn resized(mut self, axis: usize, length: Dimension) -> Self {
+        let block_end = |bin: &Self| {
+            if axis == VERTICAL_AXIS {
+                bin.dims[axis] + bin.gap
+            } else {
+                bin.dims[axis] - bin.wall_clearance + bin.gap
+            }
+        };
+        let old_end = block_end(&self);
+        self.outer_dims[axis] += length - self.dims[axis];
+        self.dims[axis] = length;
+        let new_end = block_end(&self);
+
+        self.blocks = self
+            .blocks
+            .iter()
+            .filter(|space| space.origin[axis] < new_end)
+            .map(|space| {
+                let mut lengths = space.lengths();
+                let end = space.origin[axis] + lengths[axis];
+                if end + EPSILON >= old_end || end > new_end {
+                    lengths[axis] = new_end - space.origin[axis];
+                }
+                Space::at(space.origin, lengths)
+            })
+            .collect();
+        self
+    }
+
     /**
 
     Returns a new bin that is the same dimensions as the original bin, but without any items.
@@ -107,9 +1030,103 @@ impl<'a> Bin<'a> {
     ```
     **/
     pub fn clone_as_empty_bin(&self) -> Self {
-        Self {
-            blocks: self.blocks.clone(),
+        self.clone_as_empty_bin_for()
+    }
+
+    /// Returns a new empty bin with the same dimensions and settings as the original bin, for items
+    /// with a different type of payload.
+    pub(crate) fn clone_as_empty_bin_for<U>(&self) -> Bin<'a, U> {
+        Bin {
+            dims: self.dims,
+            outer_dims: self.outer_dims,
+            blocks: self.empty_spaces(),
             items: vec![],
+            placements: vec![],
+            tolerance: self.tolerance,
+            has_load_limits: false,
+            min_support: self.min_support,
+            balancing: self.balancing,
+            loading_axis: self.loading_axis,
+            gap: self.gap,
+            wall_clearance: self.wall_clearance,
+            carrier_rules: self.carrier_rules.clone(),
+            groupings: self.groupings.clone(),
+            segregation: self.segregation.clone(),
+            compartments: self.compartments.clone(),
+            obstructions: self.obstructions.clone(),
+        }
+    }
+}
+
+/// Returns the weighted average of the centres of the placements.
+fn center_of_gravity<'p>(
+    weighted: impl Iterator<Item = (Weight, &'p Placement)>,
+) -> Option<[Dimension; 3]> {
+    let mut total_weight = 0 as Weight;
+    let mut moments = [0 as Dimension; 3];
+    for (weight, placement) in weighted {
+        total_weight += weight;
+        for (moment, center) in moments.iter_mut().zip(placement.center().iter()) {
+            *moment += weight * center;
+        }
+    }
+    if total_weight > 0 as Weight {
+        Some([
+            moments[0] / total_weight,
+            moments[1] / total_weight,
+            moments[2] / total_weight,
+        ])
+    } else {
+        None
+    }
+}
+
+/// Returns the total weight resting on each of the placed items.
+fn loads(weights: &[Weight], placements: &[Placement], tolerance: Dimension) -> Vec<Weight> {
+    // An item can only rest on items that are lower down, so working from the top down means that
+    // the full weight resting on each item is known before it's passed on to the items below.
+
+    let mut order = (0..placements.len()).collect::<Vec<usize>>();
+    order.sort_by(|&a, &b| {
+        placements[b]
+            .bottom()
+            .partial_cmp(&placements[a].bottom())
+            .unwrap_or(Equal)
+    });
+
+    let mut loads = vec![0 as Weight; placements.len()];
+    for index in order {
+        let supports = placements
+            .iter()
+            .enumerate()
+            .filter(|(_, below)| placements[index].rests_on(below, tolerance))
+            .map(|(below_index, below)| (below_index, placements[index].footprint_overlap(below)))
+            .collect::<Vec<(usize, Dimension)>>();
+        let supported_area = supports.iter().map(|(_, area)| area).sum::<Dimension>();
+        let total = weights[index] + loads[index];
+        for (below_index, area) in supports {
+            loads[below_index] += total * area / supported_area;
         }
     }
+    loads
+}
+
+/// Returns the number of items stacked on top of each of the placed items, including items that
+/// are stacked further up.
+fn stacked(placements: &[Placement], tolerance: Dimension) -> Vec<usize> {
+    (0..placements.len())
+        .map(|index| {
+            let mut above = vec![false; placements.len()];
+            let mut to_visit = vec![index];
+            while let Some(below) = to_visit.pop() {
+                for (above_index, placement) in placements.iter().enumerate() {
+                    if !above[above_index] && placement.rests_on(&placements[below], tolerance) {
+                        above[above_index] = true;
+                        to_visit.push(above_index);
+                    }
+                }
+            }
+            above.into_iter().filter(|&is_above| is_above).count()
+        })
+        .collect()
 }
@@ -1,4 +1,5 @@
 use crate::block::BestFitKind::{DoubledFit, ExactFit, GreaterThanFit};
+use crate::placement::EPSILON;
 use std::cmp::Ordering::Equal;
 
 // TODO: explore using a fixed-decimal type. (eg: u16 for the integer, and u8 for the two decmial
@@ -9,7 +10,7 @@ type Volume = f64;
 
 /// Represents the kinds of fits we support in the best-fit section of our algorithm.
 /// usize contains the index of the dim where the best-fit has been matched.
-
+#[allow(clippy::enum_variant_names)]
 enum BestFitKind {
     /// When the side of the container is more than twice the length of the item's matching side.
     DoubledFit(usize),
@@ -22,7 +23,6 @@ enum BestFitKind {
 }
 
 /// Represents a 3-dimensional cuboid.
-
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Block {
     /// The dimensions, sorted in ascending order.
@@ -31,9 +31,6 @@ pub struct Block {
 
 impl Block {
     pub fn new<F: Into<Dimension>>(d1: F, d2: F, d3: F) -> Self {
-        if 100 > i32::MAX {
-            println!("ok!");
-        }
         // TODO: fail on negative values
         let mut dims = [d1.into(), d2.into(), d3.into()];
         dims.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
@@ -45,12 +42,17 @@ impl Block {
     }
 
     /// Returns a boolean regarding whether or not an item will fit into the block.
-
     pub fn does_it_fit(&self, other: &Block) -> bool {
+        self.does_it_fit_with_tolerance(other, 0 as Dimension)
+    }
+
+    /// Returns whether or not an item will fit into the block, allowing each of the item's
+    /// dimensions to exceed the block's by up to the tolerance.
+    pub fn does_it_fit_with_tolerance(&self, other: &Block, tolerance: Dimension) -> bool {
         self.dims
             .iter()
             .zip(other.dims.iter())
-            .all(|(d, other_d)| d >= other_d)
+            .all(|(d, other_d)| d + tolerance >= *other_d)
     }
 
     /**
@@ -76,115 +78,32 @@ impl Block {
     ///   );
     /// ```
     **/
-    pub fn best_fit(mut self, item: &Block) -> Option<Vec<Block>> {
-        if !self.does_it_fit(&item) {
-            return None;
-        }
-
-        let mut blocks = vec![];
-
-        let side_1 = match self._get_best_fit(item) {
-            DoubledFit(i) => {
-                // choose the shortest side of the container we can stack the item twice on its
-                // longest side based on theory of if b_dim / 2 >= s_dim, don't open a new block (or
-                // don't rotate the item).
-
-                let block_1 = Block::new(
-                    self.dims[i] - item.dims[2],
-                    self.dims[(i + 2) % 3],
-                    self.dims[(i + 1) % 3],
-                );
-
-                // reset the container's dimensions to being the height of the item:
-
-                self.dims[i] = item.dims[2];
-
-                blocks.push(block_1);
-                i
-            }
-            ExactFit(i) => {
-                // If the item's longest side fits perfects across the length of our container, then
-                // use that side instead:
-
-                i
-            }
-            GreaterThanFit(i) => {
-                // If we can't do either of the above, then choose the shortest side of the
-                // container where we can stack the longest side of the item: i = sides.find {
-                // |side| dims[side] >= item.dims[2] }
-
-                blocks.push(Block::new(
-                    self.dims[i] - item.dims[2],
-                    item.dims[0],
-                    item.dims[1],
-                ));
-                i
-            }
-        };
-
-        let (side_2, side_3) = self._get_side_2_side_3(item, side_1);
-
-        // option one for remaining blocks
-        let block_2a = Block::new(
-            self.dims[side_1],
-            self.dims[side_2],
-            self.dims[side_3] - item.dims[0],
-        );
-        let block_3a = Block::new(
-            self.dims[side_1],
-            self.dims[side_2] - item.dims[1],
-            item.dims[0],
-        );
-
-        // option two for remaining blocks
-        let block_2b = Block::new(
-            self.dims[side_1],
-            self.dims[side_2] - item.dims[1],
-            self.dims[side_3],
-        );
-        let block_3b = Block::new(
-            self.dims[side_1],
-            self.dims[side_3] - item.dims[0],
-            item.dims[1],
-        );
-
-        // select the option where block_2 and block_3 are closest in size
-        //
-        // this operator has been tested and is 5-15% more accurate than if
-        // volume(block_2a) > volume(block_2b)
-
-        if block_2a.volume() < block_2b.volume() {
-            blocks.push(block_2a);
-            blocks.push(block_3a);
-        } else {
-            blocks.push(block_2b);
-            blocks.push(block_3b);
-        }
-
-        // if the block's smallest dimension is not 0, then it has volume, so the block should be
-        // returned as part of our results
+    pub fn best_fit(self, item: &Block) -> Option<Vec<Block>> {
+        self.best_fit_with_tolerance(item, 0 as Dimension)
+    }
 
-        let mut res = blocks
-            .into_iter()
-            .filter(|block| block.dims[0] > 0 as Dimension)
-            .collect::<Vec<Block>>();
-        res.sort_by(|block_a, block_b| {
-            block_a
-                .volume()
-                .partial_cmp(&block_b.volume())
-                .unwrap_or(Equal)
-        });
-        Some(res)
+    /// Finds the best fit in the same way as `best_fit`, but treats dimensions that are within the
+    /// tolerance of each other as being equal. Remaining blocks that are no thicker than the
+    /// tolerance are discarded.
+    pub fn best_fit_with_tolerance(self, item: &Block, tolerance: Dimension) -> Option<Vec<Block>> {
+        Space::new(self)
+            .best_fit(item, tolerance)
+            .map(|(_, spaces)| spaces.into_iter().map(|space| space.block).collect())
     }
 
     // This is a rotation method to rotate the item first checking if the item MUST be rotated in a
     // specific direction based on size constraints, then rotates it so it leaves the largest bulk
     // volume left in the container.
 
-    fn _get_side_2_side_3(&self, item: &Block, side_1: usize) -> (usize, usize) {
-        if item.dims[1] > self.dims[(side_1 + 2) % 3] {
+    fn _get_side_2_side_3(
+        &self,
+        item: &Block,
+        side_1: usize,
+        tolerance: Dimension,
+    ) -> (usize, usize) {
+        if item.dims[1] > self.dims[(side_1 + 2) % 3] + tolerance {
             ((side_1 + 1) % 3, (side_1 + 2) % 3)
-        } else if item.dims[1] > self.dims[(side_1 + 1) % 3] {
+        } else if item.dims[1] > self.dims[(side_1 + 1) % 3] + tolerance {
             ((side_1 + 2) % 3, (side_1 + 1) % 3)
         } else {
             ((side_1 + 1) % 3, (side_1 + 2) % 3)
@@ -194,19 +113,16 @@ impl Block {
     // Find the best fit where the longest side of our item fits into the shortest side of our
     // container.
 
-    fn _get_best_fit(&self, item: &Block) -> BestFitKind {
+    fn _get_best_fit(&self, item: &Block, tolerance: Dimension) -> BestFitKind {
         let doubled_fit_side = self.dims.iter().enumerate().find_map(|(i, side)| {
-            if side >= &(item.dims[2] * 2_f64) {
+            if side + tolerance >= item.dims[2] * 2_f64 {
                 Some(i)
             } else {
                 None
             }
         });
         let exact_fit_side = self.dims.iter().enumerate().find_map(|(i, dim)| {
-
-            // consider comparing these within some error: `(dim - &item.dims[2]).abs() < error`
-
-            if dim == &item.dims[2] {
+            if (dim - item.dims[2]).abs() <= tolerance {
                 Some(i)
             } else {
                 None
@@ -228,10 +144,258 @@ impl Block {
                     .dims
                     .iter()
                     .enumerate()
-                    .find_map(|(i, dim)| if dim >= &item.dims[2] { Some(i) } else { None })
+                    .find_map(|(i, dim)| {
+                        if dim + tolerance >= item.dims[2] {
+                            Some(i)
+                        } else {
+                            None
+                        }
+                    })
                     .expect("Invariant violated: item must fit within the container!");
                 GreaterThanFit(i)
             }
         }
     }
 }
+
+/// Represents a block that has been positioned inside of a bin, where each of the block's sorted
+/// dimensions runs along one of the bin's axes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct Space {
+    pub(crate) block: Block,
+    /// The corner of the space that's closest to the bin's origin.
+    pub(crate) origin: [Dimension; 3],
+    /// The bin axis that each of the block's sorted dimensions runs along.
+    pub(crate) axes: [usize; 3],
+}
+
+impl Space {
+    /// Creates a space at the bin's origin, where the block's sorted dimensions run along the bin's
+    /// axes in order.
+    pub(crate) fn new(block: Block) -> Self {
+        Self {
+            block,
+            origin: [0 as Dimension; 3],
+            axes: [0, 1, 2],
+        }
+    }
+
+    /// Creates a space at the origin of a bin with the given (unsorted) lengths along each axis.
+    pub(crate) fn from_lengths(lengths: [Dimension; 3]) -> Self {
+        Self::new(Block {
+            dims: [0 as Dimension; 3],
+        })
+        .sub_space([0 as Dimension; 3], lengths)
+    }
+
+    /// Creates a space at the origin, with the given (unsorted) lengths along each axis.
+    pub(crate) fn at(origin: [Dimension; 3], lengths: [Dimension; 3]) -> Self {
+        Self {
+            origin,
+            ..Self::from_lengths(lengths)
+        }
+    }
+
+    /// Returns the space's lengths along each of the bin's axes.
+    pub(crate) fn lengths(&self) -> [Dimension; 3] {
+        let mut lengths = [0 as Dimension; 3];
+        for (dim, axis) in self.block.dims.iter().zip(self.axes.iter()) {
+            lengths[*axis] = *dim;
+        }
+        lengths
+    }
+
+    /// Returns the parts of the space that are outside of the box with the given origin and
+    /// lengths, as spaces that don't overlap each other. The space is cut along the bin's length,
+    /// then its width, then its height, so the parts beside the box keep the space's full height.
+    pub(crate) fn without(&self, origin: [Dimension; 3], lengths: [Dimension; 3]) -> Vec<Space> {
+        let mut start = self.origin;
+        let mut end = self.origin;
+        for (end, length) in end.iter_mut().zip(self.lengths().iter()) {
+            *end += length;
+        }
+        let overlaps = (0..3).all(|axis| {
+            origin[axis] + lengths[axis] > start[axis] + EPSILON
+                && origin[axis] < end[axis] - EPSILON
+        });
+        if !overlaps {
+            return vec![*self];
+        }
+
+        let mut parts = Vec::new();
+        for axis in 0..3 {
+            let box_end = origin[axis] + lengths[axis];
+            if origin[axis] > start[axis] + EPSILON {
+                let mut part_end = end;
+                part_end[axis] = origin[axis];
+                parts.push(Space::between(start, part_end));
+                start[axis] = origin[axis];
+            }
+            if box_end < end[axis] - EPSILON {
+                let mut part_start = start;
+                part_start[axis] = box_end;
+                parts.push(Space::between(part_start, end));
+                end[axis] = box_end;
+            }
+        }
+        parts
+    }
+
+    /// Creates the space between the two corners.
+    fn between(start: [Dimension; 3], end: [Dimension; 3]) -> Self {
+        Self::at(
+            start,
+            [end[0] - start[0], end[1] - start[1], end[2] - start[2]],
+        )
+    }
+
+    /// Returns the space inside of this one that starts at the offsets, and has the lengths, along
+    /// each of this space's sorted dimensions.
+    fn sub_space(&self, offsets: [Dimension; 3], lengths: [Dimension; 3]) -> Self {
+        let mut origin = self.origin;
+        for (offset, axis) in offsets.iter().zip(self.axes.iter()) {
+            origin[*axis] += offset;
+        }
+        let mut sides = [
+            (lengths[0], self.axes[0]),
+            (lengths[1], self.axes[1]),
+            (lengths[2], self.axes[2]),
+        ];
+        sides.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Equal));
+        Self {
+            block: Block {
+                dims: [sides[0].0, sides[1].0, sides[2].0],
+            },
+            origin,
+            axes: [sides[0].1, sides[1].1, sides[2].1],
+        }
+    }
+
+    /**
+    Finds the shortest length of the space that will fit the longest length of the item, as
+    described in `Block::best_fit`.
+
+    Returns the origin and lengths of the item along each of the bin's axes, and the remaining
+    spaces around it. If an item doesn't fit, we return None.
+    **/
+    pub(crate) fn best_fit(
+        &self,
+        item: &Block,
+        tolerance: Dimension,
+    ) -> Option<(Space, Vec<Space>)> {
+        let container = self.block;
+        if !container.does_it_fit_with_tolerance(item, tolerance) {
+            return None;
+        }
+
+        // The lengths of the container along each of its sorted dimensions, which are shortened as
+        // the item is fitted.
+
+        let mut lengths = container.dims;
+        let full = [0 as Dimension; 3];
+        let mut spaces = vec![];
+
+        let best_fit = container._get_best_fit(item, tolerance);
+        let side_1 = match best_fit {
+            DoubledFit(i) | ExactFit(i) | GreaterThanFit(i) => i,
+        };
+        let (side_2, side_3) = container._get_side_2_side_3(item, side_1, tolerance);
+
+        match best_fit {
+            DoubledFit(i) => {
+                // choose the shortest side of the container we can stack the item twice on its
+                // longest side based on theory of if b_dim / 2 >= s_dim, don't open a new block (or
+                // don't rotate the item).
+
+                let mut offsets = full;
+                offsets[i] = item.dims[2];
+                let mut block_1 = lengths;
+                block_1[i] = lengths[i] - item.dims[2];
+
+                // reset the container's dimensions to being the height of the item:
+
+                lengths[i] = item.dims[2];
+
+                spaces.push(self.sub_space(offsets, block_1));
+            }
+            ExactFit(_) => {
+                // If the item's longest side fits perfects across the length of our container, then
+                // use that side instead.
+            }
+            GreaterThanFit(i) => {
+                // If we can't do either of the above, then choose the shortest side of the
+                // container where we can stack the longest side of the item: i = sides.find {
+                // |side| dims[side] >= item.dims[2] }
+
+                let mut offsets = full;
+                offsets[i] = item.dims[2];
+                let mut block_1 = lengths;
+                block_1[i] = lengths[i] - item.dims[2];
+                block_1[side_2] = item.dims[1];
+                block_1[side_3] = item.dims[0];
+                spaces.push(self.sub_space(offsets, block_1));
+            }
+        };
+
+        // The item's longest side runs along side_1, its middle side along side_2, and its
+        // shortest side along side_3.
+
+        let mut item_lengths = full;
+        item_lengths[side_1] = item.dims[2];
+        item_lengths[side_2] = item.dims[1];
+        item_lengths[side_3] = item.dims[0];
+
+        let beyond = |side: usize| {
+            let mut offsets = full;
+            offsets[side] = item_lengths[side];
+            offsets
+        };
+
+        // option one for remaining blocks
+        let mut block_2a = lengths;
+        block_2a[side_3] = lengths[side_3] - item.dims[0];
+        let mut block_3a = lengths;
+        block_3a[side_2] = lengths[side_2] - item.dims[1];
+        block_3a[side_3] = item.dims[0];
+        let block_2a = self.sub_space(beyond(side_3), block_2a);
+        let block_3a = self.sub_space(beyond(side_2), block_3a);
+
+        // option two for remaining blocks
+        let mut block_2b = lengths;
+        block_2b[side_2] = lengths[side_2] - item.dims[1];
+        let mut block_3b = lengths;
+        block_3b[side_2] = item.dims[1];
+        block_3b[side_3] = lengths[side_3] - item.dims[0];
+        let block_2b = self.sub_space(beyond(side_2), block_2b);
+        let block_3b = self.sub_space(beyond(side_3), block_3b);
+
+        // select the option where block_2 and block_3 are closest in size
+        //
+        // this operator has been tested and is 5-15% more accurate than if
+        // volume(block_2a) > volume(block_2b)
+
+        if block_2a.block.volume() < block_2b.block.volume() {
+            spaces.push(block_2a);
+            spaces.push(block_3a);
+        } else {
+            spaces.push(block_2b);
+            spaces.push(block_3b);
+        }
+
+        // if the block's smallest dimension is not 0, then it has volume, so the block should be
+        // returned as part of our results
+
+        let mut res = spaces
+            .into_iter()
+            .filter(|space| space.block.dims[0] > tolerance)
+            .collect::<Vec<Space>>();
+        res.sort_by(|space_a, space_b| {
+            space_a
+                .block
+                .volume()
+                .partial_cmp(&space_b.block.volume())
+                .unwrap_or(Equal)
+        });
+        Some((self.sub_space(full, item_lengths), res))
+    }
+}
@@ -0,0 +1,219 @@
+use crate::bin::Bin;
+use crate::block::Dimension;
+use crate::error::{Error, Result};
+use crate::item::Item;
+use crate::placement::EPSILON;
+use crate::shipping::round_up;
+use crate::strip::pack_strip;
+
+/// The axis that each candidate box is left open along while searching, which is its length.
+const OPEN_AXIS: usize = 0;
+
+/**
+Represents the limits on the boxes that a box search can choose from, such as the sizes that a
+box-making machine can cut.
+
+```rust
+    use bin_packer_3d::box_search::BoxLimits;
+    let limits = BoxLimits::default()
+        .with_min_side(5.0)
+        .with_max_side(60.0)
+        .with_increment(0.5);
+    assert_eq!(limits.max_side, Some(60.0));
+```
+**/
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BoxLimits {
+    /// The shortest that any side of the box can be.
+    pub min_side: Dimension,
+    /// The longest that any side of the box can be, if it's limited.
+    pub max_side: Option<Dimension>,
+    /// The increment that each side of the box is a multiple of, if any.
+    pub increment: Option<Dimension>,
+}
+
+impl BoxLimits {
+    /// Sets the shortest that any side of the box can be.
+    pub fn with_min_side(mut self, min_side: Dimension) -> Self {
+        self.min_side = min_side;
+        self
+    }
+
+    /// Sets the longest that any side of the box can be.
+    pub fn with_max_side(mut self, max_side: Dimension) -> Self {
+        self.max_side = Some(max_side);
+        self
+    }
+
+    /// Only allows sides that are a multiple of the increment.
+    pub fn with_increment(mut self, increment: Dimension) -> Self {
+        self.increment = Some(increment);
+        self
+    }
+
+    /// Returns whether or not the side is within the limits.
+    fn allows(&self, side: Dimension) -> bool {
+        side + EPSILON >= self.min_side
+            && self
+                .max_side
+                .is_none_or(|max_side| side <= max_side + EPSILON)
+    }
+}
+
+/// Represents the smallest box found by a box search.
+#[derive(Clone, Debug)]
+pub struct BoxFit<'a, T = ()> {
+    /// The length, width and height of the box.
+    pub dims: [Dimension; 3],
+    /// The items packed into a bin of those dimensions.
+    pub bin: Bin<'a, T>,
+}
+
+/**
+Searches for the smallest-volume box, within the limits, that all of the items can be packed into
+at once. The bin's dimensions are ignored, but its other settings, such as its gap, wall clearance
+and carrier rules, are applied to every box that's tried.
+
+Each candidate box has its width and height picked from the lengths you get by lining items up
+side by side, or from the multiples of the increment when one is set. Its length is then made as
+short as possible with `pack_strip`, which is built on the same fitting as `Bin::try_packing`.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::box_search::{smallest_box, BoxLimits};
+  use bin_packer_3d::item::Item;
+
+  let book = Item::new("book", [2.0, 15.0, 23.0]);
+  let mug = Item::new("mug", [9.0, 9.0, 10.0]);
+  let limits = BoxLimits::default().with_increment(1.0);
+
+  let found = smallest_box(Bin::new([0.0, 0.0, 0.0]), &[book, book, mug], &limits).unwrap();
+  assert_eq!(found.bin.items.len(), 3);
+  assert_eq!(found.dims, [33.0, 15.0, 9.0]);
+```
+**/
+pub fn smallest_box<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    items: &[Item<'a, T>],
+    limits: &BoxLimits,
+) -> Result<BoxFit<'a, T>> {
+    let units = items
+        .iter()
+        .filter(|item| item.quantity > 0)
+        .collect::<Vec<_>>();
+    let total_volume = units
+        .iter()
+        .map(|item| item.block.volume() * item.quantity as Dimension)
+        .sum::<Dimension>();
+
+    // Every side of the box has to be at least as long as the shortest side of each of the items,
+    // and lining every item up end to end is always long enough.
+
+    let margin = 2.0 * bin.wall_clearance();
+    let shortest_side = units
+        .iter()
+        .map(|item| item.padded_block().dims[0] + margin)
+        .fold(limits.min_side, Dimension::max);
+    let longest_side = units
+        .iter()
+        .map(|item| (item.padded_block().dims[2] + bin.gap()) * item.quantity as Dimension)
+        .sum::<Dimension>()
+        + margin;
+    let longest_side = limits
+        .max_side
+        .map_or(longest_side, |max_side| max_side.min(longest_side));
+
+    let sides = candidate_sides(&units, &bin, limits, shortest_side, longest_side);
+
+    let mut best: Option<BoxFit<'a, T>> = None;
+    let best_volume = |best: &Option<BoxFit<'a, T>>| {
+        best.as_ref()
+            .map_or(Dimension::INFINITY, |found| found.dims.iter().product())
+    };
+
+    for (height_index, &height) in sides.iter().enumerate() {
+        for &width in sides[height_index..].iter() {
+            // The box can't be any shorter than the shortest side that holds every item, nor hold
+            // less than the items' volume, and the smallest volume those allow only grows with the
+            // width, so none of the wider boxes can do any better.
+
+            let fewest_length = (total_volume / (width * height)).max(shortest_side);
+            if width * height * fewest_length >= best_volume(&best) {
+                break;
+            }
+
+            let template = bin
+                .clone_as_empty_bin()
+                .resized(1, width)
+                .resized(2, height);
+            let packing = match pack_strip(template, OPEN_AXIS, items) {
+                Ok(packing) => packing,
+                Err(Error::AllItemsMustFit(_)) | Err(Error::CarrierRuleViolated(_)) => continue,
+                Err(err) => return Err(err),
+            };
+            let length = round_up(packing.length.max(limits.min_side), limits.increment);
+            if !limits.allows(length) {
+                continue;
+            }
+            let packed = packing.bin.resized(OPEN_AXIS, length);
+            if packed
+                .carrier_rules()
+                .iter()
+                .any(|rule| !rule.is_met_by(&packed))
+            {
+                continue;
+            }
+
+            let dims = [length, width, height];
+            if dims.iter().product::<Dimension>() < best_volume(&best) {
+                best = Some(BoxFit { dims, bin: packed });
+            }
+        }
+    }
+
+    best.ok_or_else(|| {
+        Error::NoBoxFound("No box within the limits can fit all of the items.".to_string())
+    })
+}
+
+/// Returns the lengths that the width and height of a candidate box can be, in ascending order.
+fn candidate_sides<T>(
+    units: &[&Item<'_, T>],
+    bin: &Bin<'_, T>,
+    limits: &BoxLimits,
+    shortest_side: Dimension,
+    longest_side: Dimension,
+) -> Vec<Dimension> {
+    let mut sides = match limits.increment {
+        Some(increment) if increment > 0.0 => {
+            let first = round_up(shortest_side, Some(increment));
+            (0..)
+                .map(|step| first + step as Dimension * increment)
+                .take_while(|&side| side <= longest_side + EPSILON)
+                .collect::<Vec<Dimension>>()
+        }
+        _ => {
+            // Line up as many of each dimension of each item as there are units of it.
+
+            let margin = 2.0 * bin.wall_clearance() - bin.gap();
+            let mut sides = Vec::new();
+            for item in units.iter() {
+                for &dim in item.padded_block().dims.iter() {
+                    let mut side = margin;
+                    for _ in 0..item.quantity {
+                        side += dim + bin.gap();
+                        if side > longest_side + EPSILON {
+                            break;
+                        }
+                        sides.push(side.max(shortest_side));
+                    }
+                }
+            }
+            sides
+        }
+    };
+    sides.retain(|&side| limits.allows(side));
+    sides.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    sides.dedup_by(|a, b| (*a - *b).abs() <= EPSILON);
+    sides
+}
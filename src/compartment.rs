@@ -0,0 +1,74 @@
+use crate::block::Dimension;
+use crate::placement::{Placement, EPSILON};
+
+/**
+Represents one of the sections of a bin that's split up by fixed dividers, such as the frozen
+section of a grocery tote. Items can't cross from one compartment into another.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::compartment::Compartment;
+    use bin_packer_3d::item::Item;
+    let mut tote = Bin::new([6.0, 4.0, 4.0]).with_compartments(&[
+        Compartment::new([0.0, 0.0, 0.0], [2.0, 4.0, 4.0]).with_zone("frozen"),
+        Compartment::new([2.0, 0.0, 0.0], [4.0, 4.0, 4.0]).with_zone("ambient"),
+    ]);
+    let peas = Item::new("peas", [2.0, 2.0, 1.0]).with_zone("frozen");
+    let pasta = Item::new("pasta", [2.0, 2.0, 1.0]).with_zone("ambient");
+    assert!(tote.try_packing(pasta).is_some());
+    assert!(tote.try_packing(peas).is_some());
+    assert_eq!(tote.placements[0].origin, [2.0, 0.0, 0.0]);
+    assert_eq!(tote.placements[1].origin, [0.0, 0.0, 0.0]);
+```
+**/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Compartment<'a> {
+    /// The corner of the compartment that's closest to the bin's origin.
+    pub origin: [Dimension; 3],
+    /// The compartment's inner lengths along each of the bin's axes.
+    pub dims: [Dimension; 3],
+    /// The zone the compartment belongs to, such as its temperature, if it has one.
+    pub zone: Option<&'a str>,
+}
+
+impl<'a> Compartment<'a> {
+    /// Creates a compartment from its origin and inner lengths, without a zone.
+    pub fn new(origin: [Dimension; 3], dims: [Dimension; 3]) -> Self {
+        Self {
+            origin,
+            dims,
+            zone: None,
+        }
+    }
+
+    /// Sets the zone the compartment belongs to. Items that require a zone are only packed into
+    /// compartments of that zone.
+    pub fn with_zone(mut self, zone: &'a str) -> Self {
+        self.zone = Some(zone);
+        self
+    }
+
+    /// Returns whether or not an item that requires the zone can be packed into the compartment.
+    /// Items that don't require a zone can be packed into any compartment.
+    pub fn accepts(&self, zone: Option<&str>) -> bool {
+        zone.is_none() || zone == self.zone
+    }
+
+    /// Returns whether or not the point is inside of the compartment.
+    pub(crate) fn contains_point(&self, point: [Dimension; 3]) -> bool {
+        (0..3).all(|axis| {
+            point[axis] + EPSILON >= self.origin[axis]
+                && point[axis] < self.origin[axis] + self.dims[axis] - EPSILON
+        })
+    }
+
+    /// Returns whether or not the placement is entirely inside of the compartment.
+    pub fn contains(&self, placement: &Placement, tolerance: Dimension) -> bool {
+        let tolerance = tolerance.max(EPSILON);
+        (0..3).all(|axis| {
+            placement.origin[axis] + tolerance >= self.origin[axis]
+                && placement.origin[axis] + placement.dims[axis]
+                    <= self.origin[axis] + self.dims[axis] + tolerance
+        })
+    }
+}
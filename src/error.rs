@@ -13,4 +13,40 @@ pub enum Error {
     #[error("AllItemsMustFit error: `{0}`")]
     AllItemsMustFit(String),
 
+    /// Raised when the items in a bin can't be placed one at a time from the approach direction,
+    /// because some of them would have to be placed before each other.
+    ///
+    #[error("NoLoadingSequence error: `{0}`")]
+    NoLoadingSequence(String),
+
+    /// Raised when a bin breaks one of the carrier rules attached to it, or when an item is too
+    /// heavy to ever be packed into a bin without breaking one of them.
+    ///
+    #[error("CarrierRuleViolated error: `{0}`")]
+    CarrierRuleViolated(String),
+
+    /// Raised when no box within the limits of a box search can fit all of the items.
+    ///
+    #[error("NoBoxFound error: `{0}`")]
+    NoBoxFound(String),
+
+    /// Raised when the items can't be packed into the fixed number of bins they're split across.
+    ///
+    #[error("NotEnoughBins error: `{0}`")]
+    NotEnoughBins(String),
+
+    /// Raised when the items of a group that must share a bin can't all be packed into one bin.
+    ///
+    #[error("GroupingViolated error: `{0}`")]
+    GroupingViolated(String),
+
+    /// Raised when a hazmat segregation table can't be read.
+    ///
+    #[error("InvalidSegregationTable error: `{0}`")]
+    InvalidSegregationTable(String),
+
+    /// Raised when an axis, such as a bin's loading axis, isn't 0, 1 or 2.
+    ///
+    #[error("InvalidAxis error: `{0}`")]
+    InvalidAxis(String),
 }
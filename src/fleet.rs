@@ -0,0 +1,173 @@
+use crate::bin::Bin;
+use crate::block::Dimension;
+use crate::error::Result;
+use crate::item::Item;
+use crate::packing_algorithm::{check_bin, pack_each_kit, sort_for_loading, units};
+use crate::shipping::Cost;
+use crate::strategy::SortKey;
+
+/// A packed bin along with the items that were left over.
+type Fill<'a, T> = (Bin<'a, T>, Vec<Item<'a, T>>);
+
+/// Represents a type of bin in the fleet, along with how many are available and what each one
+/// costs to use.
+#[derive(Clone, Debug)]
+pub struct BinType<'a, T = ()> {
+    /// An empty bin with the type's dimensions and settings.
+    pub bin: Bin<'a, T>,
+    /// The number of bins of this type that can be used.
+    pub available: usize,
+    /// The cost of using one of the bins.
+    pub cost: Cost,
+}
+
+impl<'a, T> BinType<'a, T> {
+    /// Creates a bin type whose cost is its outer volume, so that loading the fleet as cheaply as
+    /// possible wastes as little space as possible.
+    pub fn new(bin: Bin<'a, T>, available: usize) -> Self {
+        let cost = Cost::Fixed(bin.outer_dims().iter().product());
+        Self {
+            bin,
+            available,
+            cost,
+        }
+    }
+
+    /// Sets the cost of using one of the bins, which can be a fixed cost or a carrier's rule that
+    /// each packed bin is billed by.
+    pub fn with_cost<C: Into<Cost>>(mut self, cost: C) -> Self {
+        self.cost = cost.into();
+        self
+    }
+}
+
+/// Represents how the items were loaded onto the fleet.
+#[derive(Clone, Debug)]
+pub struct FleetPacking<'a, T = ()> {
+    /// The packed bins, each along with the index of its type in the fleet.
+    pub bins: Vec<(usize, Bin<'a, T>)>,
+    /// The items that couldn't be loaded once the fleet ran out, with one entry per unit.
+    pub unloaded: Vec<Item<'a, T>>,
+    /// The total cost of the bins that were used.
+    pub total_cost: f64,
+}
+
+/**
+Loads the items onto a fleet made up of different types of bin, using no more bins of each type
+than are available. Items that can't be loaded once the fleet runs out, including items that don't
+fit into any type of bin, are reported as unloaded rather than causing an error.
+
+Bins are filled one at a time. Each time, every type with a bin still available is packed with
+the remaining items, and the type that packs the most volume for its cost is used.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::fleet::{pack_fleet, BinType};
+  use bin_packer_3d::item::Item;
+
+  let pallet = Item::new("pallet", [4.0, 4.0, 4.0]);
+  let fleet = vec![
+      BinType::new(Bin::new([16.0, 4.0, 4.0]), 2).with_cost(10.0),
+      BinType::new(Bin::new([8.0, 4.0, 4.0]), 1).with_cost(6.0),
+  ];
+
+  let packing = pack_fleet(&fleet, &[pallet.with_quantity(12)]).unwrap();
+  assert_eq!(packing.bins.len(), 3);
+  assert_eq!(packing.total_cost, 26.0);
+  assert_eq!(packing.unloaded.len(), 2);
+```
+**/
+pub fn pack_fleet<'a, T: Clone>(
+    fleet: &[BinType<'a, T>],
+    items: &[Item<'a, T>],
+) -> Result<FleetPacking<'a, T>> {
+    for bin_type in fleet {
+        check_bin(&bin_type.bin)?;
+    }
+
+    let mut remaining = units(items);
+    let mut available = fleet
+        .iter()
+        .map(|bin_type| bin_type.available)
+        .collect::<Vec<usize>>();
+    let mut packing = FleetPacking {
+        bins: Vec::new(),
+        unloaded: Vec::new(),
+        total_cost: 0.0,
+    };
+
+    while !remaining.is_empty() {
+        // Fill a bin of each type that's still available, and keep the one that packs the most
+        // volume for its cost:
+
+        let mut best: Option<((f64, f64), usize, Fill<'a, T>)> = None;
+        for (type_index, bin_type) in fleet.iter().enumerate() {
+            if available[type_index] == 0 {
+                continue;
+            }
+            let (bin, left_over) = fill_bin(&bin_type.bin, &remaining);
+            if bin.items.is_empty() {
+                continue;
+            }
+            let packed_volume = bin
+                .items
+                .iter()
+                .map(|item| item.block.volume())
+                .sum::<Dimension>();
+            // A bin that only packs items without any volume can't be compared by cost per
+            // volume, so it's ranked after every other bin and compared by its cost alone:
+
+            let cost = bin_type.cost.of(&bin);
+            let cost_per_volume = if packed_volume > 0 as Dimension {
+                cost / packed_volume
+            } else {
+                f64::INFINITY
+            };
+            if best
+                .as_ref()
+                .is_none_or(|(best, _, _)| (cost_per_volume, cost) < *best)
+            {
+                best = Some(((cost_per_volume, cost), type_index, (bin, left_over)));
+            }
+        }
+
+        match best {
+            Some((_, type_index, (bin, left_over))) => {
+                available[type_index] -= 1;
+                packing.total_cost += fleet[type_index].cost.of(&bin);
+                packing.bins.push((type_index, bin));
+                remaining = left_over;
+            }
+            None => break,
+        }
+    }
+
+    packing.unloaded = remaining;
+    Ok(packing)
+}
+
+/// Packs as many of the items as possible into an empty copy of the bin, trying each sort key in
+/// turn, and returns the bin that packs the most volume along with the items left over. Items that
+/// must share a bin are packed or left over together.
+fn fill_bin<'a, T: Clone>(bin: &Bin<'a, T>, items: &[Item<'a, T>]) -> Fill<'a, T> {
+    let mut best: Option<(Dimension, Bin<'a, T>, Vec<Item<'a, T>>)> = None;
+    for sort_key in [SortKey::LongestSide, SortKey::Volume, SortKey::LargestFace].iter() {
+        let mut sorted = items.to_vec();
+        sort_for_loading(bin, &mut sorted, |a, b| sort_key.compare(a, b));
+
+        let mut packed = bin.clone_as_empty_bin();
+        let left_over = pack_each_kit(&mut packed, sorted);
+
+        let volume = packed
+            .items
+            .iter()
+            .map(|item| item.block.volume())
+            .sum::<Dimension>();
+        if best.as_ref().is_none_or(|(best, _, _)| volume > *best) {
+            best = Some((volume, packed, left_over));
+        }
+    }
+
+    let (_, packed, left_over) = best.expect("Invalid state - every sort key is tried.");
+    (packed, left_over)
+}
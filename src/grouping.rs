@@ -0,0 +1,138 @@
+use crate::error::Error;
+use crate::item::Item;
+use std::fmt;
+
+/**
+Represents a constraint on which items can share a bin, based on the items' tags.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::grouping::Grouping;
+    use bin_packer_3d::item::Item;
+    let bleach = Item::new("bleach", [1.0, 1.0, 1.0]).with_tags(&["chemicals"]);
+    let bread = Item::new("bread", [1.0, 1.0, 1.0]).with_tags(&["food"]);
+    let mut bin =
+        Bin::new([4.0, 4.0, 4.0]).with_groupings(&[Grouping::DifferentBin("chemicals", "food")]);
+    assert!(bin.try_packing(bleach).is_some());
+    assert!(bin.try_packing(bread).is_none());
+```
+**/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Grouping<'a> {
+    /// Every item with the tag must be packed into the same bin, such as the parts of a kit.
+    SameBin(&'a str),
+
+    /// Items with the first tag must never share a bin with items with the second tag. When both
+    /// tags are the same, every item with the tag must be packed into a bin of its own.
+    DifferentBin(&'a str, &'a str),
+}
+
+impl Grouping<'_> {
+    /// Returns whether or not the two items must be packed into different bins.
+    pub fn separates<T>(&self, first: &Item<'_, T>, second: &Item<'_, T>) -> bool {
+        match *self {
+            Grouping::SameBin(_) => false,
+            Grouping::DifferentBin(a, b) => {
+                (first.has_tag(a) && second.has_tag(b)) || (first.has_tag(b) && second.has_tag(a))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Grouping<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Grouping::SameBin(tag) => write!(f, "items tagged `{}` in the same bin", tag),
+            Grouping::DifferentBin(a, b) => {
+                write!(f, "items tagged `{}` and `{}` in different bins", a, b)
+            }
+        }
+    }
+}
+
+/// Returns whether or not any of the items have to be packed into the same bin as each other.
+pub(crate) fn has_kits<T>(groupings: &[Grouping<'_>], items: &[Item<'_, T>]) -> bool {
+    groupings.iter().any(|grouping| match grouping {
+        Grouping::SameBin(tag) => {
+            items
+                .iter()
+                .filter(|item| item.has_tag(tag))
+                .map(|item| item.quantity)
+                .sum::<usize>()
+                > 1
+        }
+        Grouping::DifferentBin(_, _) => false,
+    })
+}
+
+/// Returns the error for a kit that can't be packed into a single bin.
+pub(crate) fn kit_error<T>(groupings: &[Grouping<'_>], kit: &[Item<'_, T>]) -> Error {
+    let tag = groupings
+        .iter()
+        .find_map(|grouping| match grouping {
+            Grouping::SameBin(tag) if kit.iter().any(|item| item.has_tag(tag)) => Some(*tag),
+            _ => None,
+        })
+        .unwrap_or_default();
+    Error::GroupingViolated(format!(
+        "The items tagged `{}` can't all be packed into a single bin.",
+        tag
+    ))
+}
+
+/// Splits the sorted items into kits, where each kit holds the items that must be packed into the
+/// same bin. Kits are ordered by their first item, and items stay in sorted order within each
+/// kit. Items without a `SameBin` tag are kits of their own.
+pub(crate) fn kits<'a, T>(
+    groupings: &[Grouping<'_>],
+    sorted: Vec<Item<'a, T>>,
+) -> Vec<Vec<Item<'a, T>>> {
+    let kit_tags = groupings
+        .iter()
+        .filter_map(|grouping| match grouping {
+            Grouping::SameBin(tag) => Some(*tag),
+            Grouping::DifferentBin(_, _) => None,
+        })
+        .collect::<Vec<&str>>();
+
+    // Items that share any `SameBin` tag are joined into the same kit, using a union-find over
+    // the items:
+
+    let mut parents = (0..sorted.len()).collect::<Vec<usize>>();
+    fn root(parents: &mut [usize], mut index: usize) -> usize {
+        while parents[index] != index {
+            parents[index] = parents[parents[index]];
+            index = parents[index];
+        }
+        index
+    }
+    for tag in kit_tags {
+        let mut first = None;
+        for (index, item) in sorted.iter().enumerate() {
+            if item.has_tag(tag) {
+                match first {
+                    Some(first) => {
+                        let (a, b) = (root(&mut parents, first), root(&mut parents, index));
+                        // The earlier item stays the root, so each kit keeps its first item's place.
+                        parents[a.max(b)] = a.min(b);
+                    }
+                    None => first = Some(index),
+                }
+            }
+        }
+    }
+
+    let mut kit_of_root: Vec<Option<usize>> = vec![None; sorted.len()];
+    let mut kits: Vec<Vec<Item<'a, T>>> = Vec::new();
+    for (index, item) in sorted.into_iter().enumerate() {
+        let root = root(&mut parents, index);
+        match kit_of_root[root] {
+            Some(kit) => kits[kit].push(item),
+            None => {
+                kit_of_root[root] = Some(kits.len());
+                kits.push(vec![item]);
+            }
+        }
+    }
+    kits
+}
@@ -0,0 +1,153 @@
+use crate::block::Dimension;
+use crate::error::{Error, Result};
+use crate::item::Item;
+use crate::placement::{Placement, EPSILON};
+
+/// Represents how far apart items of two hazmat classes must be kept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segregation {
+    /// The items must never share a bin.
+    DifferentBin,
+
+    /// The items can share a bin, as long as they're at least this far apart.
+    MinDistance(Dimension),
+}
+
+impl Segregation {
+    /// Returns the stricter of the two segregations.
+    fn stricter(self, other: Self) -> Self {
+        match (self, other) {
+            (Segregation::MinDistance(a), Segregation::MinDistance(b)) => {
+                Segregation::MinDistance(a.max(b))
+            }
+            _ => Segregation::DifferentBin,
+        }
+    }
+}
+
+/**
+Represents a compatibility matrix between hazmat classes, which says how far apart items of each
+pair of classes must be kept. Pairs of classes that aren't in the table can be packed next to each
+other. Each rule applies both ways around, and when a pair has more than one rule, the strictest
+one applies.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::hazmat::{Segregation, SegregationTable};
+    use bin_packer_3d::item::Item;
+    let table = SegregationTable::default()
+        .with_rule("3", "5.1", Segregation::DifferentBin)
+        .with_rule("3", "8", Segregation::MinDistance(3.0));
+    assert_eq!(table.segregation("8", "3"), Some(Segregation::MinDistance(3.0)));
+    assert_eq!(table.segregation("3", "9"), None);
+
+    let fuel = Item::new("fuel", [1.0, 1.0, 1.0]).with_hazmat_class("3");
+    let acid = Item::new("acid", [1.0, 1.0, 1.0]).with_hazmat_class("8");
+    let mut bin = Bin::new([2.0, 1.0, 1.0]).with_segregation(table);
+    assert!(bin.try_packing(fuel).is_some());
+    assert!(bin.try_packing(acid).is_none());
+```
+**/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SegregationTable<'a> {
+    rules: Vec<(&'a str, &'a str, Segregation)>,
+}
+
+impl<'a> SegregationTable<'a> {
+    /// Adds a rule for how far apart items of the two classes must be kept.
+    pub fn with_rule(mut self, first: &'a str, second: &'a str, segregation: Segregation) -> Self {
+        self.rules.push((first, second, segregation));
+        self
+    }
+
+    /**
+    Reads a table from text, such as the contents of a file. Each line holds two classes and the
+    segregation between them, separated by whitespace. The segregation is either `different-bin`,
+    or `distance` followed by the minimum distance. Blank lines, and anything after a `#`, are
+    ignored.
+
+    ```rust
+        use bin_packer_3d::hazmat::{Segregation, SegregationTable};
+        let text = "
+            3    5.1    different-bin    # flammable liquids and oxidisers
+            3    8      distance 3.0     # flammable liquids and corrosives
+        ";
+        let table = SegregationTable::parse(text).unwrap();
+        assert_eq!(table.segregation("5.1", "3"), Some(Segregation::DifferentBin));
+        assert_eq!(table.segregation("3", "8"), Some(Segregation::MinDistance(3.0)));
+    ```
+    **/
+    pub fn parse(text: &'a str) -> Result<Self> {
+        let mut table = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let invalid = |reason: &str| {
+                Error::InvalidSegregationTable(format!("Line {} {}.", index + 1, reason))
+            };
+            let segregation = match fields[..] {
+                [] => continue,
+                [_, _, "different-bin"] => Segregation::DifferentBin,
+                [_, _, "distance", distance] => match distance.parse::<Dimension>() {
+                    Ok(distance) if distance >= 0 as Dimension => {
+                        Segregation::MinDistance(distance)
+                    }
+                    _ => return Err(invalid(&format!("has an invalid distance `{}`", distance))),
+                },
+                [_, _, "distance"] => return Err(invalid("is missing a distance")),
+                [_, _, "different-bin", extra, ..] | [_, _, "distance", _, extra, ..] => {
+                    return Err(invalid(&format!("has an unexpected field `{}`", extra)))
+                }
+                [_, _, segregation, ..] => {
+                    return Err(invalid(&format!(
+                        "has an unknown segregation `{}`",
+                        segregation
+                    )))
+                }
+                _ => return Err(invalid("needs two classes and a segregation")),
+            };
+            table = table.with_rule(fields[0], fields[1], segregation);
+        }
+        Ok(table)
+    }
+
+    /// Returns how far apart items of the two classes must be kept, if they must be kept apart at
+    /// all.
+    pub fn segregation(&self, first: &str, second: &str) -> Option<Segregation> {
+        self.rules
+            .iter()
+            .filter(|(a, b, _)| (*a == first && *b == second) || (*a == second && *b == first))
+            .map(|(_, _, segregation)| *segregation)
+            .reduce(Segregation::stricter)
+    }
+
+    /// Returns whether or not the table has any rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns how far apart the two items must be kept, if they must be kept apart at all.
+    pub(crate) fn between<T>(
+        &self,
+        first: &Item<'_, T>,
+        second: &Item<'_, T>,
+    ) -> Option<Segregation> {
+        self.segregation(first.hazmat_class?, second.hazmat_class?)
+    }
+
+    /// Returns whether or not the two placed items are kept far enough apart.
+    pub(crate) fn is_met_by<T>(
+        &self,
+        first: (&Item<'_, T>, &Placement),
+        second: (&Item<'_, T>, &Placement),
+        tolerance: Dimension,
+    ) -> bool {
+        match self.between(first.0, second.0) {
+            None => true,
+            Some(Segregation::DifferentBin) => false,
+            Some(Segregation::MinDistance(distance)) => {
+                first.1.distance_to(second.1) + tolerance.max(EPSILON) >= distance
+            }
+        }
+    }
+}
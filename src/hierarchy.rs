@@ -0,0 +1,148 @@
+use crate::bin::Bin;
+use crate::block::Dimension;
+use crate::error::Result;
+use crate::item::{Item, Weight};
+use crate::packing_algorithm::pack_bins;
+
+/// Represents what an item in a multi-level packing is: either one of the original items, or a
+/// bin that was packed at the level below.
+#[derive(Clone, Debug)]
+pub enum Contents<'a, T = ()> {
+    /// One of the original items, holding its payload.
+    Sku(T),
+
+    /// A bin packed at the level below, such as a carton on a pallet.
+    Packed(Box<Bin<'a, Contents<'a, T>>>),
+}
+
+impl<'a, T> Contents<'a, T> {
+    /// Returns the packed bin, if this is a bin from the level below.
+    pub fn bin(&self) -> Option<&Bin<'a, Contents<'a, T>>> {
+        match self {
+            Contents::Sku(_) => None,
+            Contents::Packed(bin) => Some(bin),
+        }
+    }
+}
+
+/// Represents one level of a multi-level packing, such as cartons, pallets or containers.
+#[derive(Clone, Debug)]
+pub struct Level<'a, T = ()> {
+    /// The id that every bin packed at this level is given, once it's an item of the next level.
+    pub id: &'a str,
+    /// An empty bin with the level's dimensions and settings.
+    pub bin: Bin<'a, Contents<'a, T>>,
+    /// The weight of an empty bin, which is added to the weight of what's packed into it.
+    pub tare: Weight,
+    /// The padding around each packed bin, once it's an item of the next level.
+    pub padding: Dimension,
+}
+
+impl<'a, T> Level<'a, T> {
+    /// Creates a level from an empty bin, whose bins weigh nothing when they're empty and have no
+    /// padding.
+    pub fn new(id: &'a str, bin: Bin<'a>) -> Self {
+        Self {
+            id,
+            bin: bin.for_payload(),
+            tare: 0 as Weight,
+            padding: 0 as Dimension,
+        }
+    }
+
+    /// Sets the weight of an empty bin.
+    pub fn with_tare(mut self, tare: Weight) -> Self {
+        self.tare = tare;
+        self
+    }
+
+    /// Sets the padding around each packed bin, such as stretch wrap around a pallet.
+    pub fn with_padding(mut self, padding: Dimension) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns the packed bin as an item for the next level, using its outer dimensions and its
+    /// total weight.
+    fn as_item(&self, bin: Bin<'a, Contents<'a, T>>) -> Item<'a, Contents<'a, T>> {
+        let weight = self.tare + bin.total_weight();
+        Item::new(self.id, bin.outer_dims())
+            .with_weight(weight)
+            .with_padding(self.padding)
+            .with_data(Contents::Packed(Box::new(bin)))
+    }
+}
+
+/**
+Packs the items through each of the levels in turn, such as into cartons, then onto pallets, then
+into containers. The bins packed at each level become the items of the next level, using their
+outer dimensions, their weight including the level's tare, and the level's padding. Each level is
+packed with `pack_bins`.
+
+Returns the bins of the last level. Each packed item's data says whether it's one of the original
+items or a bin from the level below, so the result can be walked as a tree from the top level
+down to the original items.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::hierarchy::{pack_levels, skus, Level};
+  use bin_packer_3d::item::Item;
+
+  let mug = Item::new("mug", [1.0, 1.0, 1.0]).with_weight(0.5);
+  let levels = vec![
+      Level::new("carton", Bin::new([2.0, 2.0, 2.0]).with_wall_thickness(0.5)).with_tare(1.0),
+      Level::new("pallet", Bin::new([6.0, 3.0, 3.0])),
+  ];
+
+  let pallets = pack_levels(&[mug.with_quantity(20)], &levels).unwrap();
+  assert_eq!(pallets.len(), 2);
+  assert_eq!(pallets[0].items.len(), 2);
+  assert_eq!(pallets[0].items[0].id, "carton");
+  assert_eq!(pallets[0].items[0].weight, 5.0);
+  assert_eq!(skus(&pallets[0]).len(), 16);
+```
+**/
+pub fn pack_levels<'a, T: Clone>(
+    items: &[Item<'a, T>],
+    levels: &[Level<'a, T>],
+) -> Result<Vec<Bin<'a, Contents<'a, T>>>> {
+    let mut items = items
+        .iter()
+        .map(|item| item.clone().with_data(Contents::Sku(item.data.clone())))
+        .collect::<Vec<Item<'a, Contents<'a, T>>>>();
+    let mut bins = Vec::new();
+
+    for (index, level) in levels.iter().enumerate() {
+        bins = pack_bins(level.bin.clone_as_empty_bin(), &items)?;
+        if index + 1 < levels.len() {
+            items = bins.drain(..).map(|bin| level.as_item(bin)).collect();
+        }
+    }
+
+    Ok(bins)
+}
+
+/**
+Returns every one of the original items inside of the bin, walking down through the bins packed
+at each level below it.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::hierarchy::{skus, Contents};
+  use bin_packer_3d::item::Item;
+
+  let mut carton = Bin::new([2.0, 2.0, 2.0]).for_payload();
+  carton.try_packing(Item::new("mug", [1.0, 1.0, 1.0]).with_data(Contents::Sku(())));
+  assert_eq!(skus(&carton)[0].id, "mug");
+```
+**/
+pub fn skus<'b, 'a, T>(bin: &'b Bin<'a, Contents<'a, T>>) -> Vec<&'b Item<'a, Contents<'a, T>>> {
+    let mut found = Vec::new();
+    for item in bin.items.iter() {
+        match &item.data {
+            Contents::Sku(_) => found.push(item),
+            Contents::Packed(child) => found.extend(skus(child)),
+        }
+    }
+    found
+}
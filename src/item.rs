@@ -8,17 +8,46 @@ use std::cmp::Ordering::Equal;
 ///
 pub type ItemId = str;
 
+/// The weight of an item, in whichever unit the caller chooses to use consistently.
+pub type Weight = f64;
+
 /// Represents an item that a user will insert into a bin.
+///
+/// Each item can carry an arbitrary user payload, which is passed through the packing algorithm
+/// untouched. Items created with `Item::new` have an empty `()` payload.
+///
 /// ```rust
 ///   use bin_packer_3d::item::Item;
 ///   let item = Item::new("deck", [2.0, 8.0, 12.0]);
 /// ```
 #[derive(Clone, Debug, Copy)]
-pub struct Item<'a> {
+pub struct Item<'a, T = ()> {
     /// a string slice of the id
     pub id: &'a ItemId,
     /// a Block
     pub block: Block,
+    /// the user payload attached to this item
+    pub data: T,
+    /// the number of identical units this item represents
+    pub quantity: usize,
+    /// the weight of a single unit
+    pub weight: Weight,
+    /// the most weight that can rest on top of the item, if it's limited
+    pub max_load: Option<Weight>,
+    /// the most items that can be stacked on top of the item, if it's limited
+    pub max_stack: Option<usize>,
+    /// the delivery stop the item is unloaded at, if it's part of a multi-drop route
+    pub delivery_stop: Option<usize>,
+    /// the space kept clear around every side of the item, such as for protective wrapping
+    pub padding: Dimension,
+    /// the value of a single unit, if it differs from the unit's volume
+    pub value: Option<f64>,
+    /// the tags that the bin's groupings refer to the item by
+    pub tags: &'a [&'a str],
+    /// the item's dangerous goods class, if it's hazardous
+    pub hazmat_class: Option<&'a str>,
+    /// the zone of the compartment the item must be packed into, if it needs one
+    pub zone: Option<&'a str>,
 }
 
 impl<'a> Item<'a> {
@@ -27,15 +56,276 @@ impl<'a> Item<'a> {
         Self {
             id,
             block: Block::new(dims[0], dims[1], dims[2]),
+            data: (),
+            quantity: 1,
+            weight: 0 as Weight,
+            max_load: None,
+            max_stack: None,
+            delivery_stop: None,
+            padding: 0 as Dimension,
+            value: None,
+            tags: &[],
+            hazmat_class: None,
+            zone: None,
         }
     }
+}
+
+impl<'a, T> Item<'a, T> {
+    /**
+    Attach a user payload to the item, replacing any existing payload.
+
+    ```rust
+        use bin_packer_3d::item::Item;
+        let item = Item::new("deck", [2.0, 8.0, 12.0]).with_data(("order-1", 499));
+        assert_eq!(item.data, ("order-1", 499));
+    ```
+    **/
+    pub fn with_data<U>(self, data: U) -> Item<'a, U> {
+        Item {
+            id: self.id,
+            block: self.block,
+            data,
+            quantity: self.quantity,
+            weight: self.weight,
+            max_load: self.max_load,
+            max_stack: self.max_stack,
+            delivery_stop: self.delivery_stop,
+            padding: self.padding,
+            value: self.value,
+            tags: self.tags,
+            hazmat_class: self.hazmat_class,
+            zone: self.zone,
+        }
+    }
+
+    /**
+    Set the number of identical units this item represents, so that large orders of a single SKU
+    don't need to repeat the same item.
+
+    ```rust
+        use bin_packer_3d::item::Item;
+        let item = Item::new("deck", [2.0, 8.0, 12.0]).with_quantity(5_000);
+        assert_eq!(item.quantity, 5_000);
+    ```
+    **/
+    pub fn with_quantity(mut self, quantity: usize) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    /**
+    Set the weight of a single unit of the item.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let brick = Item::new("brick", [2.0, 1.0, 1.0]).with_weight(2.5);
+        let mut bin = Bin::new([4.0, 1.0, 1.0]);
+        bin.try_packing(brick);
+        bin.try_packing(brick);
+        assert_eq!(bin.total_weight(), 5.0);
+    ```
+    **/
+    pub fn with_weight(mut self, weight: Weight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /**
+    Limit the total weight that can rest on top of the item, including the weight of items that
+    are stacked further up. The limit applies to whichever face of the item ends up on top.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let eggs = Item::new("eggs", [4.0, 4.0, 2.0]).with_weight(1.0).with_max_load(2.0);
+        let anvil = Item::new("anvil", [4.0, 4.0, 2.0]).with_weight(50.0);
+        let mut bin = Bin::new([4.0, 4.0, 4.0]);
+        assert!(bin.try_packing(eggs).is_some());
+        assert!(bin.try_packing(anvil).is_none());
+    ```
+    **/
+    pub fn with_max_load(mut self, max_load: Weight) -> Self {
+        self.max_load = Some(max_load);
+        self
+    }
+
+    /**
+    Limit the number of items that can be stacked on top of the item, including items that are
+    stacked further up.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let plate = Item::new("plate", [4.0, 4.0, 1.0]).with_max_stack(1);
+        let mut bin = Bin::new([4.0, 4.0, 4.0]);
+        assert!(bin.try_packing(plate).is_some());
+        assert!(bin.try_packing(plate).is_some());
+        assert!(bin.try_packing(plate).is_none());
+    ```
+    **/
+    pub fn with_max_stack(mut self, max_stack: usize) -> Self {
+        self.max_stack = Some(max_stack);
+        self
+    }
+
+    /**
+    Set the delivery stop the item is unloaded at, where the first stop is unloaded first. In a
+    bin with a loading axis, items for later stops are never placed where they'd block access to
+    items for earlier stops.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let first = Item::new("first", [2.0, 2.0, 2.0]).with_delivery_stop(1);
+        let last = Item::new("last", [2.0, 2.0, 2.0]).with_delivery_stop(2);
+        let mut bin = Bin::new([2.0, 2.0, 4.0]).with_loading_axis(0);
+        assert!(bin.try_packing(first).is_some());
+        assert!(bin.try_packing(last).is_none());
+    ```
+    **/
+    pub fn with_delivery_stop(mut self, delivery_stop: usize) -> Self {
+        self.delivery_stop = Some(delivery_stop);
+        self
+    }
+
+    /**
+    Keep the given amount of space clear around every side of the item, such as for protective
+    wrapping. The padding is taken into account when fitting the item, but the item's placement
+    still reports its true dimensions.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::item::Item;
+        let item = Item::new("vase", [2.0, 2.0, 2.0]).with_padding(1.0);
+        assert!(Bin::new([4.0, 4.0, 4.0]).fits(&item));
+        assert!(!Bin::new([3.0, 4.0, 4.0]).fits(&item));
+    ```
+    **/
+    pub fn with_padding(mut self, padding: Dimension) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /**
+    Set the value of a single unit of the item, which is what knapsack packing maximises. Items
+    without a value are worth their volume.
+
+    ```rust
+        use bin_packer_3d::item::Item;
+        let deck = Item::new("deck", [2.0, 8.0, 12.0]);
+        assert_eq!(deck.value(), 192.0);
+        assert_eq!(deck.with_value(15.0).value(), 15.0);
+    ```
+    **/
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Returns the value of a single unit of the item, which defaults to its volume.
+    pub fn value(&self) -> f64 {
+        self.value.unwrap_or_else(|| self.block.volume())
+    }
+
+    /**
+    Set the tags that the bin's groupings refer to the item by, such as the kit it belongs to or
+    the kind of goods it is.
+
+    ```rust
+        use bin_packer_3d::item::Item;
+        let bleach = Item::new("bleach", [2.0, 2.0, 6.0]).with_tags(&["chemicals"]);
+        assert!(bleach.has_tag("chemicals"));
+        assert!(!bleach.has_tag("food"));
+    ```
+    **/
+    pub fn with_tags(mut self, tags: &'a [&'a str]) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /**
+    Set the item's dangerous goods class, such as `"3"` or `"5.1"`, which the bin's segregation
+    table uses to keep it apart from incompatible goods.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::hazmat::{Segregation, SegregationTable};
+        use bin_packer_3d::item::Item;
+        let table = SegregationTable::default().with_rule("3", "5.1", Segregation::DifferentBin);
+        let fuel = Item::new("fuel", [1.0, 1.0, 1.0]).with_hazmat_class("3");
+        let bleach = Item::new("bleach", [1.0, 1.0, 1.0]).with_hazmat_class("5.1");
+        let mut bin = Bin::new([4.0, 4.0, 4.0]).with_segregation(table);
+        assert!(bin.try_packing(fuel).is_some());
+        assert!(bin.try_packing(bleach).is_none());
+    ```
+    **/
+    pub fn with_hazmat_class(mut self, hazmat_class: &'a str) -> Self {
+        self.hazmat_class = Some(hazmat_class);
+        self
+    }
+
+    /**
+    Require the item to be packed into one of the bin's compartments of the zone, such as
+    `"frozen"`. The item doesn't fit into bins without a compartment of that zone.
+
+    ```rust
+        use bin_packer_3d::bin::Bin;
+        use bin_packer_3d::compartment::Compartment;
+        use bin_packer_3d::item::Item;
+        let peas = Item::new("peas", [1.0, 1.0, 1.0]).with_zone("frozen");
+        assert!(!Bin::new([4.0, 2.0, 2.0]).fits(&peas));
+
+        let mut tote = Bin::new([4.0, 2.0, 2.0]).with_compartments(&[
+            Compartment::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]).with_zone("ambient"),
+            Compartment::new([2.0, 0.0, 0.0], [2.0, 2.0, 2.0]).with_zone("frozen"),
+        ]);
+        assert!(tote.try_packing(peas).is_some());
+        assert_eq!(tote.placements[0].origin, [2.0, 0.0, 0.0]);
+    ```
+    **/
+    pub fn with_zone(mut self, zone: &'a str) -> Self {
+        self.zone = Some(zone);
+        self
+    }
+
+    /// Returns whether or not the item has the tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Returns the block the item takes up once it's been padded on every side.
+    pub(crate) fn padded_block(&self) -> Block {
+        let [d1, d2, d3] = self.block.dims;
+        let padding = 2.0 * self.padding;
+        Block::new(d1 + padding, d2 + padding, d3 + padding)
+    }
+
+    /// Returns whether or not the item limits what can be stacked on top of it.
+    pub(crate) fn has_load_limits(&self) -> bool {
+        self.max_load.is_some() || self.max_stack.is_some()
+    }
+
+    /// Returns whether or not the two items would be packed in exactly the same way.
+    pub(crate) fn packs_like(&self, other: &Self) -> bool {
+        self.block == other.block
+            && self.weight == other.weight
+            && self.max_load == other.max_load
+            && self.max_stack == other.max_stack
+            && self.delivery_stop == other.delivery_stop
+            && self.padding == other.padding
+            && self.tags == other.tags
+            && self.hazmat_class == other.hazmat_class
+            && self.zone == other.zone
+    }
 
     fn get_largest_dim(&self) -> Dimension {
         self.block.dims[2]
     }
 }
 
-impl Ord for Item<'_> {
+impl<T> Ord for Item<'_, T> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.get_largest_dim()
             .partial_cmp(&other.get_largest_dim())
@@ -43,16 +333,16 @@ impl Ord for Item<'_> {
     }
 }
 
-impl PartialOrd for Item<'_> {
+impl<T> PartialOrd for Item<'_, T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(&other))
+        Some(self.cmp(other))
     }
 }
 
-impl PartialEq for Item<'_> {
+impl<T> PartialEq for Item<'_, T> {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
     }
 }
 
-impl<'a> Eq for Item<'a> {}
+impl<T> Eq for Item<'_, T> {}
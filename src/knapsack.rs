@@ -0,0 +1,94 @@
+use crate::bin::Bin;
+use crate::error::Result;
+use crate::item::Item;
+use crate::packing_algorithm::{check_bin, pack_each_kit, sort_for_loading, units};
+use crate::strategy::{Order, SortKey};
+
+/// Represents the result of knapsack packing, where as much value as possible is packed into a
+/// single bin.
+#[derive(Clone, Debug)]
+pub struct Knapsack<'a, T = ()> {
+    /// The packed bin, whose items are the chosen items.
+    pub bin: Bin<'a, T>,
+    /// The items that were left out of the bin, with one entry per unit.
+    pub rejected: Vec<Item<'a, T>>,
+}
+
+impl<'a, T> Knapsack<'a, T> {
+    /// Returns the items that were packed into the bin, with one entry per unit.
+    pub fn chosen(&self) -> &[Item<'a, T>] {
+        &self.bin.items
+    }
+
+    /// Returns the total value of the items that were packed into the bin.
+    pub fn value(&self) -> f64 {
+        self.bin.items.iter().map(|item| item.value()).sum()
+    }
+}
+
+/**
+Packs as much value as possible into a single bin, for when there are more items than fit. Each
+item is worth its value, which defaults to its volume, so items without a value fill as much of
+the bin as possible. Items that don't fit, or that aren't worth anything, are returned as rejected
+rather than causing an error.
+
+The items are packed greedily in a few different orders, such as by value per unit of volume and
+by size, and the order that packs the most value is kept.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+  use bin_packer_3d::knapsack::pack_knapsack;
+
+  let gold = Item::new("gold", [1.0, 1.0, 1.0]).with_value(100.0);
+  let sand = Item::new("sand", [2.0, 2.0, 2.0]).with_value(10.0);
+
+  let knapsack = pack_knapsack(Bin::new([2.0, 2.0, 2.0]), &[sand, gold.with_quantity(4)]).unwrap();
+  assert_eq!(knapsack.value(), 400.0);
+  assert_eq!(knapsack.chosen().len(), 4);
+  assert_eq!(knapsack.rejected[0].id, "sand");
+```
+**/
+pub fn pack_knapsack<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    items: &[Item<'a, T>],
+) -> Result<Knapsack<'a, T>> {
+    check_bin(&bin)?;
+
+    let (units, mut worthless) = units(items)
+        .into_iter()
+        .partition::<Vec<Item<'a, T>>, _>(|item| item.value() > 0.0);
+
+    let orders = [
+        Order::ValuePerVolume,
+        Order::ValuePerWeight,
+        Order::Value,
+        Order::Size(SortKey::LongestSide),
+        Order::Size(SortKey::Volume),
+        Order::Size(SortKey::LargestFace),
+    ];
+
+    let mut best: Option<Knapsack<'a, T>> = None;
+    for order in orders.iter() {
+        let mut sorted = units.clone();
+        sort_for_loading(&bin, &mut sorted, |a, b| order.compare(a, b));
+        let knapsack = pack_greedily(bin.clone_as_empty_bin(), sorted);
+        if best
+            .as_ref()
+            .is_none_or(|best| knapsack.value() > best.value())
+        {
+            best = Some(knapsack);
+        }
+    }
+
+    let mut best = best.expect("Invalid state - at least one order is always tried.");
+    best.rejected.append(&mut worthless);
+    Ok(best)
+}
+
+/// Packs each of the sorted items into the bin if it fits, and rejects it otherwise. Items that
+/// must share a bin are packed or rejected together.
+fn pack_greedily<'a, T: Clone>(mut bin: Bin<'a, T>, sorted: Vec<Item<'a, T>>) -> Knapsack<'a, T> {
+    let rejected = pack_each_kit(&mut bin, sorted);
+    Knapsack { bin, rejected }
+}
@@ -5,9 +5,7 @@
     missing_docs,
     missing_doc_code_examples
 )]
-
 // To use the `unsafe` keyword, change to `#![allow(unsafe_code)]` (do not remove); aids auditing.
-
 #![forbid(unsafe_code)]
 
 /*!
@@ -76,8 +74,58 @@ pub mod bin;
 /// A struct representing the items we'll be packing into the bin.
 pub mod item;
 
+/// A struct representing where an item has been placed inside of a bin.
+pub mod placement;
+
+/// Defines a verifier, which checks a packed bin against its dimensions and constraints.
+pub mod verify;
+
+/// Defines how to work out an order in which the items in a bin can be physically loaded.
+pub mod sequence;
+
+/// Defines constraints on which items can share a bin.
+pub mod grouping;
+
+/// Defines the compartments that a bin can be split into, such as temperature zones.
+pub mod compartment;
+
+/// Defines how items of different hazmat classes are kept apart.
+pub mod hazmat;
+
+/// Defines multi-level packing, where the bins packed at one level are the items of the next.
+pub mod hierarchy;
+
+/// Defines strip packing, where one side of the bin is left open and kept as short as possible.
+pub mod strip;
+
+/// Defines a search for the smallest box that all of a set of items can be packed into.
+pub mod box_search;
+
+/// Defines how to choose which box sizes to stock, based on a history of orders.
+pub mod assortment;
+
+/// Defines knapsack packing, where as much value as possible is packed into a single bin.
+pub mod knapsack;
+
+/// Defines how to split items evenly across a fixed number of bins.
+pub mod balance;
+
+/// Defines how to load items onto a fleet of bins of different types, with a limited number of
+/// each.
+pub mod fleet;
+
+/// Defines how carriers work out the weight that a packed bin is billed at.
+pub mod shipping;
+
 /// Defines an Error type and a Result type, which can be raised from the packing algorithm.
 pub mod error;
 
+/// Defines the strategies that the packing algorithm can be configured with, and how to pick the
+/// best packing across several strategies.
+pub mod strategy;
+
+/// Defines an anytime solver, which returns the best packing it can find within a time budget.
+pub mod anytime;
+
 #[cfg(test)]
 mod tests;
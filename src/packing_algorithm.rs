@@ -1,6 +1,14 @@
 use crate::bin::Bin;
+use crate::block::{Block, Dimension};
 use crate::error::{Error, Result};
+use crate::grouping::{has_kits, kit_error, kits};
 use crate::item::{Item, ItemId};
+use crate::placement::EPSILON;
+use crate::shipping::CarrierRule;
+use crate::strategy::{Algorithm, Strategy};
+use std::cmp::Ordering;
+use std::iter;
+use std::ops::Range;
 
 /**
 While loop to pack items into a bin, using a First Fit Descending approach.
@@ -25,63 +33,461 @@ bins. (first bin is first nested list, second is the second, etc.)
   assert_eq!(packed_items, Ok(vec![vec!["deck", "deck", "deck", "deck"], vec!["die"]]));
 ```
 **/
-
-pub fn packing_algorithm<'a>(
-    bin: Bin<'a>,
-    items: &[Item<'a>],
+pub fn packing_algorithm<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    items: &[Item<'a, T>],
 ) -> Result<Vec<Vec<&'a ItemId>>> {
-    if !items.iter().all(|item| bin.fits(item)) {
-        return Err(Error::AllItemsMustFit(format!(
-            "All items must fit within the bin dimensions."
-        )));
+    // map the bins back into their Vec<ItemId> representations:
+
+    Ok(pack_bins(bin, items)?
+        .into_iter()
+        .map(|bin| bin.items.into_iter().map(|item| item.id).collect())
+        .collect())
+}
+
+/**
+Packs the items using the same approach as `packing_algorithm`, but returns the packed bins
+themselves, so that each item's payload is available alongside the bin it was packed into.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+  use bin_packer_3d::packing_algorithm::pack_bins;
+
+  let deck = Item::new("deck", [2.0, 8.0, 12.0]).with_data("SKU-1");
+  let die = Item::new("die", [8.0, 8.0, 8.0]).with_data("SKU-2");
+
+  let bins = pack_bins(Bin::new([8.0, 8.0, 12.0]).for_payload(), &[deck, die]).unwrap();
+  assert_eq!(bins.len(), 2);
+  assert_eq!(bins[0].items[0].data, "SKU-1");
+  assert_eq!(bins[1].items[0].data, "SKU-2");
+```
+**/
+pub fn pack_bins<'a, T: Clone>(bin: Bin<'a, T>, items: &[Item<'a, T>]) -> Result<Vec<Bin<'a, T>>> {
+    pack_bins_with_strategy(bin, items, &Strategy::default())
+}
+
+/**
+Packs the items into bins using the given strategy, which controls the order the items are packed
+in, how many bins are kept open while packing, and the tolerance used when fitting items.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+  use bin_packer_3d::packing_algorithm::pack_bins_with_strategy;
+  use bin_packer_3d::strategy::{Algorithm, SortKey, Strategy};
+
+  let deck = Item::new("deck", [2.0, 8.0, 12.0]);
+  let die = Item::new("die", [8.0, 8.0, 8.0]);
+  let strategy = Strategy {
+      sort_key: SortKey::Volume,
+      algorithm: Algorithm::AllBinsOpen,
+      tolerance: None,
+  };
+
+  let bins = pack_bins_with_strategy(Bin::new([8.0, 8.0, 12.0]), &[deck, die, deck], &strategy);
+  assert_eq!(bins.map(|bins| bins.len()), Ok(2));
+```
+**/
+pub fn pack_bins_with_strategy<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    items: &[Item<'a, T>],
+    strategy: &Strategy,
+) -> Result<Vec<Bin<'a, T>>> {
+    let bin = match strategy.tolerance {
+        Some(tolerance) => bin.with_tolerance(tolerance),
+        None => bin,
+    };
+    check_all_items_fit(&bin, items)?;
+    check_carrier_rules(&bin, items)?;
+
+    // Expand each item into one unit per quantity:
+
+    let mut items_to_pack = units(items);
+
+    // Sort the items in descending order, based on the strategy's sort key:
+
+    sort_for_loading(&bin, &mut items_to_pack, |a, b| {
+        strategy.sort_key.compare(a, b)
+    });
+
+    // Items that must share a bin are packed together as kits:
+
+    if has_kits(bin.groupings(), &items_to_pack) {
+        let kits = kits(bin.groupings(), items_to_pack);
+        return pack_kits(bin, kits, strategy.algorithm);
+    }
+
+    Ok(match strategy.algorithm {
+        Algorithm::OneBinAtATime => pack_one_bin_at_a_time(bin, items_to_pack),
+        Algorithm::AllBinsOpen => pack_all_bins_open(bin, items_to_pack),
+    })
+}
+
+/// Sorts the items into the order they're packed in, which is descending order under `compare`.
+/// When the bin is loaded through a door, items for later delivery stops are packed first, so they
+/// end up deepest in the bin.
+pub(crate) fn sort_for_loading<'a, T, F>(bin: &Bin<'a, T>, items: &mut [Item<'a, T>], compare: F)
+where
+    F: Fn(&Item<'a, T>, &Item<'a, T>) -> Ordering,
+{
+    if bin.loading_axis().is_some() {
+        items.sort_by(|a, b| {
+            b.delivery_stop
+                .cmp(&a.delivery_stop)
+                .then_with(|| compare(b, a))
+        });
+    } else {
+        items.sort_by(|a, b| compare(b, a));
+    }
+}
+
+/// Expands each item into one unit per quantity.
+pub(crate) fn units<'a, T: Clone>(items: &[Item<'a, T>]) -> Vec<Item<'a, T>> {
+    items
+        .iter()
+        .flat_map(|item| iter::repeat_n(item.clone().with_quantity(1), item.quantity))
+        .collect()
+}
+
+/// Packs each of the sorted items into the bin if it fits, and returns the items that are left
+/// over. Items that must share a bin are packed or left over together.
+pub(crate) fn pack_each_kit<'a, T: Clone>(
+    bin: &mut Bin<'a, T>,
+    sorted: Vec<Item<'a, T>>,
+) -> Vec<Item<'a, T>> {
+    let mut left_over = Vec::new();
+    for kit in kits(bin.groupings(), sorted) {
+        if !bin.try_packing_kit(&kit) {
+            left_over.extend(kit);
+        }
+    }
+    left_over
+}
+
+/// Packs the sorted items into one bin at a time, only opening a new bin once none of the
+/// remaining items fit into the current one.
+fn pack_one_bin_at_a_time<'a, T>(
+    bin: Bin<'a, T>,
+    items_to_pack: Vec<Item<'a, T>>,
+) -> Vec<Bin<'a, T>> {
+    // Group consecutive identical items into runs. Identical items fit into exactly the same
+    // blocks, so once one item of a run doesn't fit into a bin, none of the others will.
+
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    for (index, item) in items_to_pack.iter().enumerate() {
+        match runs.last_mut() {
+            Some(run) if items_to_pack[run.start].packs_like(item) => run.end = index + 1,
+            _ => runs.push(index..index + 1),
+        }
+    }
+
+    // For each run, find the smallest dimensions among the items from that run onwards. If a bin
+    // can't fit those dimensions, then it can't fit any of those items.
+
+    let infinite = Dimension::INFINITY;
+    let mut smallest_remaining = vec![Block::new(infinite, infinite, infinite); runs.len() + 1];
+    for (run_index, run) in runs.iter().enumerate().rev() {
+        let mut smallest = smallest_remaining[run_index + 1];
+        for (dim, item_dim) in smallest
+            .dims
+            .iter_mut()
+            .zip(items_to_pack[run.start].block.dims.iter())
+        {
+            *dim = dim.min(*item_dim);
+        }
+        smallest_remaining[run_index] = smallest;
+    }
+
+    // The runs that still have unpacked items form a linked list in sorted order, so that packed
+    // runs can be removed without shifting or copying the remaining items. `end` marks the end of
+    // the list.
+
+    let end = runs.len();
+    let mut next_unpacked = (1..=end).collect::<Vec<usize>>();
+    let mut first_unpacked = 0;
+    let mut items_to_pack = items_to_pack.into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut packed_bins: Vec<Bin<'a, T>> = Vec::new();
+
+    while first_unpacked != end {
+        let mut bin_currently_packing = bin.clone_as_empty_bin();
+
+        // The remaining space in a bin only ever shrinks, so an item that doesn't fit won't fit
+        // later on either, which lets us pack each bin in a single pass over the unpacked runs.
+        // The exception is a bin with a minimum support, where items packed later can support an
+        // item that didn't fit before, so the skipped runs are tried again after each item that's
+        // packed.
+
+        let rescan = bin.min_support() > 0.0;
+        let mut previous_unpacked = None;
+        let mut current = first_unpacked;
+        while current != end && bin_currently_packing.fits_block(&smallest_remaining[current]) {
+            let run = &mut runs[current];
+            let mut restart = false;
+            while run.start < run.end {
+                let item = items_to_pack[run.start]
+                    .take()
+                    .expect("Invalid state - the item has already been packed.");
+                match bin_currently_packing.find_fit(&item) {
+                    Some(fit) => {
+                        bin_currently_packing.pack_fit(fit, item);
+                        run.start += 1;
+                        if rescan && previous_unpacked.is_some() {
+                            restart = true;
+                            break;
+                        }
+                    }
+                    None => {
+                        items_to_pack[run.start] = Some(item);
+                        break;
+                    }
+                }
+            }
+            if run.start == run.end {
+                match previous_unpacked {
+                    Some(previous) => next_unpacked[previous] = next_unpacked[current],
+                    None => first_unpacked = next_unpacked[current],
+                }
+            } else {
+                previous_unpacked = Some(current);
+            }
+            if restart {
+                previous_unpacked = None;
+                current = first_unpacked;
+            } else {
+                current = next_unpacked[current];
+            }
+        }
+
+        packed_bins.push(bin_currently_packing);
     }
 
-    let mut items_to_pack = items.to_owned();
+    packed_bins
+}
+
+/// Packs each of the sorted items into the first bin that it fits into, keeping every bin open
+/// until all of the items are packed.
+fn pack_all_bins_open<'a, T>(bin: Bin<'a, T>, items_to_pack: Vec<Item<'a, T>>) -> Vec<Bin<'a, T>> {
+    let mut packed_bins: Vec<Bin<'a, T>> = Vec::new();
 
-    // Sort the items in descending order, where order is based on the longest dimension:
+    for item in items_to_pack {
+        let packed_into = packed_bins
+            .iter()
+            .enumerate()
+            .find_map(|(bin_index, packed_bin)| {
+                packed_bin.find_fit(&item).map(|fit| (bin_index, fit))
+            });
+        match packed_into {
+            Some((bin_index, fit)) => packed_bins[bin_index].pack_fit(fit, item),
+            None => {
+                let mut new_bin = bin.clone_as_empty_bin();
+                let fit = new_bin
+                    .find_fit(&item)
+                    .expect("Invalid state - the item doesn't fit into an empty bin.");
+                new_bin.pack_fit(fit, item);
+                packed_bins.push(new_bin);
+            }
+        }
+    }
 
-    items_to_pack.sort_by(|a, b| b.cmp(&a));
+    packed_bins
+}
 
-    let mut packed_bins: Vec<Bin<'a>> = Vec::new();
-    let mut bin_currently_packing = bin.clone_as_empty_bin();
+/// Packs the sorted kits using the algorithm, where every item of a kit is packed into the same
+/// bin.
+fn pack_kits<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    kits: Vec<Vec<Item<'a, T>>>,
+    algorithm: Algorithm,
+) -> Result<Vec<Bin<'a, T>>> {
+    let mut packed_bins: Vec<Bin<'a, T>> = Vec::new();
 
-    loop {
-        match (
-            items_to_pack.is_empty(),
-            bin_currently_packing.items.is_empty(),
-        ) {
-            (true, true) => break,
-            (true, false) => {
-                // no more items to pack:
+    match algorithm {
+        Algorithm::OneBinAtATime => {
+            let mut remaining = kits;
+            while !remaining.is_empty() {
+                let mut bin_currently_packing = bin.clone_as_empty_bin();
+                let mut unpacked = Vec::new();
+                for kit in remaining {
+                    if !bin_currently_packing.try_packing_kit(&kit) {
+                        unpacked.push(kit);
+                    }
+                }
+                if bin_currently_packing.items.is_empty() {
+                    return Err(kit_error(bin.groupings(), &unpacked[0]));
+                }
                 packed_bins.push(bin_currently_packing);
-                break;
+                remaining = unpacked;
             }
-            (false, _) => {
-                if let Some(packed_item_index) = items_to_pack
-                    .clone()
-                    .into_iter()
-                    .enumerate()
-                    .find_map(|(item_index, item)| {
-                        bin_currently_packing.try_packing(item).map(|_| item_index)
-                    })
+        }
+        Algorithm::AllBinsOpen => {
+            for kit in kits {
+                if !packed_bins
+                    .iter_mut()
+                    .any(|packed_bin| packed_bin.try_packing_kit(&kit))
                 {
-                    items_to_pack.remove(packed_item_index);
-                } else {
-                    // We can't fit any more items into the current bin - add it to our packed_bins, and
-                    // open up a new bin to pack.
-
-                    let packed_bin =
-                        std::mem::replace(&mut bin_currently_packing, bin.clone_as_empty_bin());
-                    packed_bins.push(packed_bin);
+                    let mut new_bin = bin.clone_as_empty_bin();
+                    if !new_bin.try_packing_kit(&kit) {
+                        return Err(kit_error(bin.groupings(), &kit));
+                    }
+                    packed_bins.push(new_bin);
                 }
             }
         }
     }
 
-    // map the bins back into their Vec<ItemId> representations:
+    Ok(packed_bins)
+}
+
+/**
+Packs items that represent many identical units as groups, rather than one unit at a time.
+
+Uses the same First Fit Decreasing approach as `packing_algorithm`, but each group is packed
+repeatedly until it's exhausted or no longer fits, which keeps the running time near-linear in
+the number of units. Returns a list of lists of the item groups in their bins, where each item's
+`quantity` is the number of units of that item packed into the bin.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+  use bin_packer_3d::packing_algorithm::pack_grouped;
+
+  let sku = Item::new("SKU-A", [1, 1, 1]).with_quantity(60);
+
+  let packed_items = pack_grouped(Bin::new([3, 3, 3]), &[sku]).unwrap();
+  assert_eq!(
+      packed_items
+          .iter()
+          .map(|bin| bin.iter().map(|item| (item.id, item.quantity)).collect())
+          .collect::<Vec<Vec<(&str, usize)>>>(),
+      vec![vec![("SKU-A", 27)], vec![("SKU-A", 27)], vec![("SKU-A", 6)]]
+  );
+```
+**/
+pub fn pack_grouped<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    items: &[Item<'a, T>],
+) -> Result<Vec<Vec<Item<'a, T>>>> {
+    check_all_items_fit(&bin, items)?;
+    check_carrier_rules(&bin, items)?;
+
+    // Items that must share a bin are packed one unit at a time, so that each kit can be kept
+    // together, and then regrouped. Each unit carries the index of the item it came from, so that
+    // units are only regrouped with units of the same item:
+
+    if has_kits(bin.groupings(), items) {
+        let indexed = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| item.clone().with_data(index))
+            .collect::<Vec<Item<'a, usize>>>();
+        let bins =
+            pack_bins_with_strategy(bin.clone_as_empty_bin_for(), &indexed, &Strategy::default())?;
+        return Ok(bins
+            .into_iter()
+            .map(|bin| regroup(items, bin.items))
+            .collect());
+    }
 
-    Ok(packed_bins
+    let mut groups = items.to_owned();
+
+    // Sort the groups in descending order, where order is based on the longest dimension:
+
+    sort_for_loading(&bin, &mut groups, |a, b| a.cmp(b));
+
+    let mut remaining = groups
+        .iter()
+        .map(|group| group.quantity)
+        .collect::<Vec<usize>>();
+    let mut packed_groups = Vec::new();
+
+    while remaining.iter().any(|&quantity| quantity > 0) {
+        let mut bin_currently_packing = bin.clone_as_empty_bin();
+        let mut packed_in_bin = Vec::new();
+
+        // The remaining space in a bin only ever shrinks, so once a group stops fitting we can move
+        // on to the next group without revisiting it.
+
+        for (group, quantity) in groups.iter().zip(remaining.iter_mut()) {
+            let mut packed = 0;
+            while *quantity > 0
+                && bin_currently_packing
+                    .try_packing(group.clone().with_quantity(1))
+                    .is_some()
+            {
+                *quantity -= 1;
+                packed += 1;
+            }
+            if packed > 0 {
+                packed_in_bin.push(group.clone().with_quantity(packed));
+            }
+        }
+        packed_groups.push(packed_in_bin);
+    }
+
+    Ok(packed_groups)
+}
+
+/// Merges the units packed into a bin, each holding the index of the item it came from, into one
+/// copy of each item with the number of its units in the bin, in the order they were first packed.
+fn regroup<'a, T: Clone>(items: &[Item<'a, T>], units: Vec<Item<'a, usize>>) -> Vec<Item<'a, T>> {
+    let mut counts: Vec<(usize, usize)> = Vec::new();
+    for unit in units {
+        match counts.iter_mut().find(|(index, _)| *index == unit.data) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((unit.data, 1)),
+        }
+    }
+    counts
         .into_iter()
-        .map(|bin| bin.items.into_iter().map(|item| item.id).collect())
-        .collect())
+        .map(|(index, count)| items[index].clone().with_quantity(count))
+        .collect()
+}
+
+pub(crate) fn check_all_items_fit<'a, T>(bin: &Bin<'a, T>, items: &[Item<'a, T>]) -> Result<()> {
+    if items.iter().all(|item| bin.fits(item)) {
+        Ok(())
+    } else {
+        Err(Error::AllItemsMustFit(
+            "All items must fit within the bin dimensions.".to_string(),
+        ))
+    }
+}
+
+pub(crate) fn check_carrier_rules<'a, T>(bin: &Bin<'a, T>, items: &[Item<'a, T>]) -> Result<()> {
+    check_bin(bin)?;
+    for rule in bin.carrier_rules() {
+        if let CarrierRule::MaxWeight(max_weight) = rule {
+            if let Some(item) = items.iter().find(|item| item.weight > max_weight + EPSILON) {
+                return Err(Error::CarrierRuleViolated(format!(
+                    "The item `{}` is too heavy for the carrier's {}.",
+                    item.id, rule
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks the bin's loading axis, and the carrier rules that only depend on the bin and not on
+/// what's packed into it.
+pub(crate) fn check_bin<T>(bin: &Bin<'_, T>) -> Result<()> {
+    if let Some(axis) = bin.loading_axis().filter(|&axis| axis >= 3) {
+        return Err(Error::InvalidAxis(format!(
+            "The bin's loading axis is {}, but it must be 0, 1 or 2.",
+            axis
+        )));
+    }
+    if let Some(rule) = bin
+        .carrier_rules()
+        .iter()
+        .find(|rule| !rule.is_met_by_empty(bin))
+    {
+        return Err(Error::CarrierRuleViolated(format!(
+            "The bin breaks the carrier's {}.",
+            rule
+        )));
+    }
+    Ok(())
 }
@@ -0,0 +1,145 @@
+use crate::block::Dimension;
+
+/// The smallest difference between two coordinates that isn't treated as rounding error.
+pub(crate) const EPSILON: Dimension = 1e-9;
+
+/// The index of the vertical axis. Bins are defined by their length, width and height, so the
+/// height runs along the z axis.
+pub const VERTICAL_AXIS: usize = 2;
+
+/**
+Represents where an item has been placed inside of a bin.
+
+Coordinates are given as `[x, y, z]`, where the bin's origin is the corner at the bottom of the
+bin, and z is the vertical axis.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::item::Item;
+    let mut bin = Bin::new([4.0, 4.0, 4.0]);
+    bin.try_packing(Item::new("item1", [4.0, 4.0, 2.0]));
+    bin.try_packing(Item::new("item2", [4.0, 4.0, 2.0]));
+    assert_eq!(bin.placements[0].bottom(), 0.0);
+    assert_eq!(bin.placements[1].bottom(), 2.0);
+```
+**/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    /// The corner of the item that's closest to the bin's origin.
+    pub origin: [Dimension; 3],
+    /// The item's lengths along each of the bin's axes, once it has been rotated into place.
+    pub dims: [Dimension; 3],
+}
+
+impl Placement {
+    /// Returns the height of the item's bottom face.
+    pub fn bottom(&self) -> Dimension {
+        self.origin[VERTICAL_AXIS]
+    }
+
+    /// Returns the height of the item's top face.
+    pub fn top(&self) -> Dimension {
+        self.origin[VERTICAL_AXIS] + self.dims[VERTICAL_AXIS]
+    }
+
+    /// Returns the centre of the item.
+    pub fn center(&self) -> [Dimension; 3] {
+        [
+            self.origin[0] + self.dims[0] / 2.0,
+            self.origin[1] + self.dims[1] / 2.0,
+            self.origin[2] + self.dims[2] / 2.0,
+        ]
+    }
+
+    /// Returns the space the item takes up once it's been padded on every side.
+    pub fn padded(&self, padding: Dimension) -> Placement {
+        Placement {
+            origin: [
+                self.origin[0] - padding,
+                self.origin[1] - padding,
+                self.origin[2] - padding,
+            ],
+            dims: [
+                self.dims[0] + 2.0 * padding,
+                self.dims[1] + 2.0 * padding,
+                self.dims[2] + 2.0 * padding,
+            ],
+        }
+    }
+
+    /// Returns the area of the item's bottom face.
+    pub fn base_area(&self) -> Dimension {
+        self.dims[0] * self.dims[1]
+    }
+
+    /// Returns the area where the two items overlap, when looking down from above.
+    pub fn footprint_overlap(&self, other: &Placement) -> Dimension {
+        (0..VERTICAL_AXIS)
+            .map(|axis| self.overlap_along(other, axis))
+            .product()
+    }
+
+    /// Returns whether or not this item sits directly on top of the other item.
+    pub fn rests_on(&self, other: &Placement, tolerance: Dimension) -> bool {
+        (self.bottom() - other.top()).abs() <= tolerance.max(EPSILON)
+            && self.footprint_overlap(other) > tolerance.max(EPSILON)
+    }
+
+    /**
+    Returns whether or not this item has to be moved before the other item can be unloaded
+    through a door at the far end of the loading axis, either because it's between the other item
+    and the door, or because it's above the other item.
+
+    ```rust
+        use bin_packer_3d::placement::Placement;
+        let deep = Placement { origin: [0.0, 0.0, 0.0], dims: [2.0, 2.0, 2.0] };
+        let near_door = Placement { origin: [2.0, 0.0, 0.0], dims: [2.0, 2.0, 2.0] };
+        assert!(near_door.blocks_unloading(&deep, 0, 0.0));
+        assert!(!deep.blocks_unloading(&near_door, 0, 0.0));
+    ```
+    **/
+    pub fn blocks_unloading(
+        &self,
+        other: &Placement,
+        loading_axis: usize,
+        tolerance: Dimension,
+    ) -> bool {
+        let tolerance = tolerance.max(EPSILON);
+        let blocks_along = |axis: usize| {
+            self.origin[axis] + tolerance >= other.origin[axis] + other.dims[axis]
+                && (0..3)
+                    .filter(|&other_axis| other_axis != axis)
+                    .all(|other_axis| self.overlap_along(other, other_axis) > tolerance)
+        };
+        blocks_along(loading_axis) || blocks_along(VERTICAL_AXIS)
+    }
+
+    /**
+    Returns the shortest distance between the two items, which is zero if they touch or overlap.
+
+    ```rust
+        use bin_packer_3d::placement::Placement;
+        let first = Placement { origin: [0.0, 0.0, 0.0], dims: [1.0, 1.0, 1.0] };
+        let second = Placement { origin: [4.0, 5.0, 0.0], dims: [1.0, 1.0, 1.0] };
+        assert_eq!(first.distance_to(&second), 5.0);
+    ```
+    **/
+    pub fn distance_to(&self, other: &Placement) -> Dimension {
+        (0..3)
+            .map(|axis| {
+                let before = other.origin[axis] - (self.origin[axis] + self.dims[axis]);
+                let after = self.origin[axis] - (other.origin[axis] + other.dims[axis]);
+                before.max(after).max(0 as Dimension)
+            })
+            .map(|gap| gap * gap)
+            .sum::<Dimension>()
+            .sqrt()
+    }
+
+    /// Returns the length along the axis where the two items overlap.
+    pub(crate) fn overlap_along(&self, other: &Placement, axis: usize) -> Dimension {
+        let start = self.origin[axis].max(other.origin[axis]);
+        let end = (self.origin[axis] + self.dims[axis]).min(other.origin[axis] + other.dims[axis]);
+        (end - start).max(0 as Dimension)
+    }
+}
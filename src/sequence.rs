@@ -0,0 +1,141 @@
+use crate::bin::Bin;
+use crate::block::Dimension;
+use crate::error::{Error, Result};
+use crate::placement::{Placement, EPSILON, VERTICAL_AXIS};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Represents the direction that items are moved in from when they're loaded into a bin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Approach {
+    /// Items are lowered into place from above.
+    Above,
+
+    /// Items are pushed into place through the far end of the given axis, where the axes are
+    /// numbered in the same order as the bin's length, width and height.
+    Side(usize),
+}
+
+impl Approach {
+    fn axis(&self) -> usize {
+        match self {
+            Approach::Above => VERTICAL_AXIS,
+            Approach::Side(axis) => *axis,
+        }
+    }
+}
+
+/**
+Returns an order in which the items in the bin can be loaded, as indexes into the bin's `items`.
+
+Each item is only loaded once everything it rests on has been loaded, and once there's a clear path
+to its position from the approach direction. The path is as wide as the item, plus the gripper
+clearance on every side other than below it, and runs from the item out to the edge of the bin.
+Where there's a choice, items are loaded in the order they were packed. Returns an `InvalidAxis`
+error if the axis of a side approach isn't 0, 1 or 2.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::item::Item;
+    use bin_packer_3d::sequence::{loading_sequence, Approach};
+
+    let mut bin = Bin::new([4.0, 2.0, 3.0]);
+    bin.try_packing(Item::new("tall", [2.0, 2.0, 3.0]));
+    bin.try_packing(Item::new("short", [2.0, 2.0, 1.0]));
+    assert_eq!(loading_sequence(&bin, Approach::Above, 0.0), Ok(vec![0, 1]));
+
+    // the gripper needs room beside the short item, so it has to go in before the tall one
+    assert_eq!(loading_sequence(&bin, Approach::Above, 0.5), Ok(vec![1, 0]));
+```
+**/
+pub fn loading_sequence<T>(
+    bin: &Bin<'_, T>,
+    approach: Approach,
+    gripper_clearance: Dimension,
+) -> Result<Vec<usize>> {
+    if let Approach::Side(axis @ 3..) = approach {
+        return Err(Error::InvalidAxis(format!(
+            "The approach axis is {}, but it must be 0, 1 or 2.",
+            axis
+        )));
+    }
+
+    let placements = bin.padded_placements();
+    let tolerance = bin.tolerance();
+
+    // Work out which items have to be loaded before each item:
+
+    let mut before = vec![Vec::new(); placements.len()];
+    let mut waiting_on = vec![0; placements.len()];
+    for (index, placement) in placements.iter().enumerate() {
+        let path = path(placement, approach, gripper_clearance, bin.dims());
+        for (other_index, other) in placements.iter().enumerate() {
+            if other_index == index {
+                continue;
+            }
+            let must_follow = placement.rests_on(other, tolerance);
+            let must_precede =
+                (0..3).all(|axis| path.overlap_along(other, axis) > tolerance.max(EPSILON));
+            if must_follow {
+                before[other_index].push(index);
+                waiting_on[index] += 1;
+            } else if must_precede {
+                before[index].push(other_index);
+                waiting_on[other_index] += 1;
+            }
+        }
+    }
+
+    // Load the items in topological order, preferring the order they were packed in:
+
+    let mut ready = waiting_on
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count == 0)
+        .map(|(index, _)| Reverse(index))
+        .collect::<BinaryHeap<Reverse<usize>>>();
+    let mut sequence = Vec::with_capacity(placements.len());
+    while let Some(Reverse(index)) = ready.pop() {
+        sequence.push(index);
+        for &next in before[index].iter() {
+            waiting_on[next] -= 1;
+            if waiting_on[next] == 0 {
+                ready.push(Reverse(next));
+            }
+        }
+    }
+
+    if sequence.len() == placements.len() {
+        Ok(sequence)
+    } else {
+        Err(Error::NoLoadingSequence(format!(
+            "{} of the {} items can't be loaded from the approach direction.",
+            placements.len() - sequence.len(),
+            placements.len()
+        )))
+    }
+}
+
+/// Returns the space that has to be clear to move the item into place, from the edge of the bin.
+fn path(
+    placement: &Placement,
+    approach: Approach,
+    gripper_clearance: Dimension,
+    bin_dims: [Dimension; 3],
+) -> Placement {
+    let approach_axis = approach.axis();
+    let mut origin = placement.origin;
+    let mut dims = placement.dims;
+    for axis in 0..3 {
+        if axis == approach_axis {
+            origin[axis] = placement.origin[axis] + placement.dims[axis];
+            dims[axis] = bin_dims[axis] - origin[axis];
+        } else if axis == VERTICAL_AXIS {
+            dims[axis] += gripper_clearance;
+        } else {
+            origin[axis] -= gripper_clearance;
+            dims[axis] += 2.0 * gripper_clearance;
+        }
+    }
+    Placement { origin, dims }
+}
@@ -0,0 +1,216 @@
+use crate::bin::Bin;
+use crate::block::Dimension;
+use crate::item::Weight;
+use crate::placement::EPSILON;
+use std::cmp::Ordering::Equal;
+use std::fmt;
+
+/**
+Represents a carrier's rule for working out the dimensional weight of a bin from its outer
+dimensions, and the billable weight that the carrier charges for.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::item::Item;
+    use bin_packer_3d::shipping::DimWeight;
+
+    // inches and pounds, rounding each side and the final weight up to the next whole number
+    let rule = DimWeight::new(139.0)
+        .with_side_rounding(1.0)
+        .with_weight_rounding(1.0);
+
+    let mut bin = Bin::new([11.5, 8.5, 5.5]);
+    bin.try_packing(Item::new("book", [11.0, 8.0, 2.0]).with_weight(3.2));
+    assert_eq!(rule.dimensional_weight(&bin), (12.0 * 9.0 * 6.0) / 139.0);
+    assert_eq!(rule.billable_weight(&bin), 5.0);
+```
+**/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DimWeight {
+    /// The divisor that the bin's volume is divided by, such as 139 for inches and pounds.
+    pub divisor: f64,
+    /// The increment that each side is rounded up to before working out the volume, if any.
+    pub side_increment: Option<Dimension>,
+    /// The increment that the billable weight is rounded up to, if any.
+    pub weight_increment: Option<Weight>,
+}
+
+impl DimWeight {
+    /// Creates a rule that divides the bin's outer volume by the divisor, without any rounding.
+    pub fn new(divisor: f64) -> Self {
+        Self {
+            divisor,
+            side_increment: None,
+            weight_increment: None,
+        }
+    }
+
+    /// Rounds each of the bin's outer dimensions up to the next multiple of the increment before
+    /// working out its volume.
+    pub fn with_side_rounding(mut self, increment: Dimension) -> Self {
+        self.side_increment = Some(increment);
+        self
+    }
+
+    /// Rounds the billable weight up to the next multiple of the increment.
+    pub fn with_weight_rounding(mut self, increment: Weight) -> Self {
+        self.weight_increment = Some(increment);
+        self
+    }
+
+    /// Returns the dimensional weight of the bin, based on its outer dimensions.
+    pub fn dimensional_weight<T>(&self, bin: &Bin<'_, T>) -> Weight {
+        let volume = bin
+            .outer_dims()
+            .iter()
+            .map(|&side| round_up(side, self.side_increment))
+            .product::<Dimension>();
+        volume / self.divisor
+    }
+
+    /// Returns the weight that the carrier bills the bin at, which is the higher of the bin's
+    /// actual weight and its dimensional weight.
+    pub fn billable_weight<T>(&self, bin: &Bin<'_, T>) -> Weight {
+        let weight = bin.total_weight().max(self.dimensional_weight(bin));
+        round_up(weight, self.weight_increment)
+    }
+
+    /// Returns the total billable weight of the bins.
+    pub fn total_billable_weight<T>(&self, bins: &[Bin<'_, T>]) -> Weight {
+        bins.iter().map(|bin| self.billable_weight(bin)).sum()
+    }
+}
+
+/**
+Represents what it costs to ship or use a packed bin, either a fixed amount for every bin, or the
+bin's billable weight under a carrier's rule.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::item::Item;
+    use bin_packer_3d::shipping::{Cost, DimWeight};
+    let cost = Cost::from(DimWeight::new(100.0));
+    let mut bin = Bin::new([10.0, 10.0, 10.0]);
+    assert_eq!(cost.of(&bin), 10.0);
+    bin.try_packing(Item::new("anvil", [5.0, 5.0, 5.0]).with_weight(25.0));
+    assert_eq!(cost.of(&bin), 25.0);
+    assert_eq!(Cost::from(4.0).of(&bin), 4.0);
+```
+**/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cost {
+    /// Every bin costs the same, however it's packed.
+    Fixed(f64),
+
+    /// Each bin costs its billable weight under the carrier's rule, so that choosing the cheapest
+    /// bins minimises the total billable weight.
+    BillableWeight(DimWeight),
+}
+
+impl Cost {
+    /// Returns what the bin costs, packed as it is.
+    pub fn of<T>(&self, bin: &Bin<'_, T>) -> f64 {
+        match self {
+            Cost::Fixed(cost) => *cost,
+            Cost::BillableWeight(rule) => rule.billable_weight(bin),
+        }
+    }
+}
+
+impl From<f64> for Cost {
+    fn from(cost: f64) -> Self {
+        Cost::Fixed(cost)
+    }
+}
+
+impl From<DimWeight> for Cost {
+    fn from(rule: DimWeight) -> Self {
+        Cost::BillableWeight(rule)
+    }
+}
+
+/// Rounds the value up to the next multiple of the increment, allowing for rounding error so that
+/// exact multiples aren't rounded up.
+pub(crate) fn round_up(value: f64, increment: Option<f64>) -> f64 {
+    match increment {
+        Some(increment) if increment > 0.0 => {
+            let multiples = value / increment;
+            let nearest = multiples.round();
+            if (multiples - nearest).abs() <= EPSILON {
+                nearest * increment
+            } else {
+                multiples.ceil() * increment
+            }
+        }
+        _ => value,
+    }
+}
+
+/**
+Represents one of a carrier's limits on the bins it accepts. Limits on size apply to the bin's
+outer dimensions.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::shipping::CarrierRule;
+
+    let bin = Bin::new([60, 30, 20]);
+    assert!(CarrierRule::MaxLengthPlusGirth(165.0).is_met_by(&bin));
+    assert!(!CarrierRule::MaxSide(48.0).is_met_by(&bin));
+    assert_eq!(CarrierRule::MaxSide(48.0).to_string(), "max side of 48");
+```
+**/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CarrierRule {
+    /// The longest side of the bin can't be longer than this.
+    MaxSide(Dimension),
+
+    /// The longest side of the bin, plus twice each of the other two sides, can't be more than
+    /// this.
+    MaxLengthPlusGirth(Dimension),
+
+    /// The packed items can't weigh more than this.
+    MaxWeight(Weight),
+
+    /// The volume of the bin can't be more than this.
+    MaxVolume(Dimension),
+}
+
+impl CarrierRule {
+    /// Returns whether or not the bin, as it's currently packed, meets the rule.
+    pub fn is_met_by<T>(&self, bin: &Bin<'_, T>) -> bool {
+        match self {
+            CarrierRule::MaxWeight(max_weight) => bin.total_weight() <= max_weight + EPSILON,
+            _ => self.is_met_by_empty(bin),
+        }
+    }
+
+    /// Returns whether or not the bin meets the rule before any items are packed into it.
+    pub(crate) fn is_met_by_empty<T>(&self, bin: &Bin<'_, T>) -> bool {
+        let mut sides = bin.outer_dims();
+        sides.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
+        match self {
+            CarrierRule::MaxSide(max_side) => sides[2] <= max_side + EPSILON,
+            CarrierRule::MaxLengthPlusGirth(max_length_plus_girth) => {
+                sides[2] + 2.0 * (sides[0] + sides[1]) <= max_length_plus_girth + EPSILON
+            }
+            CarrierRule::MaxWeight(_) => true,
+            CarrierRule::MaxVolume(max_volume) => {
+                sides.iter().product::<Dimension>() <= max_volume + EPSILON
+            }
+        }
+    }
+}
+
+impl fmt::Display for CarrierRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CarrierRule::MaxSide(max_side) => write!(f, "max side of {}", max_side),
+            CarrierRule::MaxLengthPlusGirth(max_length_plus_girth) => {
+                write!(f, "max length + girth of {}", max_length_plus_girth)
+            }
+            CarrierRule::MaxWeight(max_weight) => write!(f, "max weight of {}", max_weight),
+            CarrierRule::MaxVolume(max_volume) => write!(f, "max volume of {}", max_volume),
+        }
+    }
+}
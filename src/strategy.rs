@@ -0,0 +1,243 @@
+use crate::balance::FillMeasure;
+use crate::bin::Bin;
+use crate::block::Dimension;
+use crate::error::Result;
+use crate::item::{Item, Weight};
+use crate::packing_algorithm::pack_bins_with_strategy;
+use crate::shipping::DimWeight;
+use std::cmp::Ordering;
+use std::cmp::Ordering::Equal;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Represents the key that items are sorted by, in descending order, before they're packed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortKey {
+    /// Sort by the item's longest side. This is the First Fit Decreasing order used by
+    /// `packing_algorithm`.
+    LongestSide,
+
+    /// Sort by the item's volume.
+    Volume,
+
+    /// Sort by the area of the item's largest face.
+    LargestFace,
+}
+
+impl SortKey {
+    pub(crate) fn compare<T>(&self, a: &Item<'_, T>, b: &Item<'_, T>) -> Ordering {
+        match self {
+            SortKey::LongestSide => a.cmp(b),
+            SortKey::Volume => a
+                .block
+                .volume()
+                .partial_cmp(&b.block.volume())
+                .unwrap_or(Equal),
+            SortKey::LargestFace => (a.block.dims[1] * a.block.dims[2])
+                .partial_cmp(&(b.block.dims[1] * b.block.dims[2]))
+                .unwrap_or(Equal),
+        }
+    }
+}
+
+/// Represents the order that items are packed in by the packers that try a few different orders
+/// and keep the best packing, from the highest key to the lowest.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Order {
+    /// The item's value per unit of the space it takes up.
+    ValuePerVolume,
+    /// The item's value per unit of weight, for when the bin's weight limit is what runs out.
+    ValuePerWeight,
+    /// The item's value.
+    Value,
+    /// How much the item adds to a bin's fill, with the item's size breaking ties.
+    Fill(FillMeasure),
+    /// The item's size, which packs the bin the most tightly.
+    Size(SortKey),
+}
+
+impl Order {
+    pub(crate) fn compare<T>(&self, a: &Item<'_, T>, b: &Item<'_, T>) -> Ordering {
+        let key = |item: &Item<'_, T>| match self {
+            Order::ValuePerVolume => item.value() / item.padded_block().volume(),
+            Order::ValuePerWeight if item.weight > 0 as Weight => item.value() / item.weight,
+            Order::ValuePerWeight => f64::INFINITY,
+            Order::Value => item.value(),
+            Order::Fill(measure) => measure.of(item),
+            Order::Size(_) => 0.0,
+        };
+        match self {
+            Order::Size(sort_key) => sort_key.compare(a, b),
+            _ => key(a)
+                .partial_cmp(&key(b))
+                .unwrap_or(Equal)
+                .then_with(|| a.cmp(b)),
+        }
+    }
+}
+
+/// Represents how many bins are kept open while items are being packed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Algorithm {
+    /// Fill one bin at a time, and only open a new bin once none of the remaining items fit into
+    /// the current one. This is the approach used by `packing_algorithm`.
+    OneBinAtATime,
+
+    /// Keep every bin open, and pack each item into the first bin that it fits into.
+    AllBinsOpen,
+}
+
+/// Represents a single configuration of the packing algorithm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Strategy {
+    /// The key that items are sorted by before they're packed.
+    pub sort_key: SortKey,
+    /// How many bins are kept open while packing.
+    pub algorithm: Algorithm,
+    /// Overrides the bin's tolerance when fitting items, if set.
+    pub tolerance: Option<Dimension>,
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        Self {
+            sort_key: SortKey::LongestSide,
+            algorithm: Algorithm::OneBinAtATime,
+            tolerance: None,
+        }
+    }
+}
+
+impl Strategy {
+    /**
+    Returns every combination of sort key and algorithm, starting with the default strategy.
+
+    ```rust
+        use bin_packer_3d::strategy::Strategy;
+        let strategies = Strategy::all();
+        assert_eq!(strategies.len(), 6);
+        assert_eq!(strategies[0], Strategy::default());
+    ```
+    **/
+    pub fn all() -> Vec<Self> {
+        let sort_keys = [SortKey::LongestSide, SortKey::Volume, SortKey::LargestFace];
+        let algorithms = [Algorithm::OneBinAtATime, Algorithm::AllBinsOpen];
+        algorithms
+            .iter()
+            .flat_map(|&algorithm| {
+                sort_keys.iter().map(move |&sort_key| Self {
+                    sort_key,
+                    algorithm,
+                    tolerance: None,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Represents the objective used to compare packings, where a lower score is better.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Objective {
+    /// Minimise the number of bins used.
+    FewestBins,
+
+    /// Minimise the number of bins used, then minimise how far the worst-balanced bin's centre of
+    /// gravity is from the centre of its floor.
+    Balance,
+
+    /// Minimise the total weight that the carrier bills for the bins, using the carrier's
+    /// dimensional weight rule.
+    BillableWeight(DimWeight),
+}
+
+impl Objective {
+    /// Scores the packed bins, where a lower score is better.
+    pub fn score<T>(&self, bins: &[Bin<'_, T>]) -> f64 {
+        match self {
+            Objective::FewestBins => bins.len() as f64,
+            Objective::Balance => {
+                // The offset is scaled against half of the floor's diagonal, which is as far as
+                // the centre of gravity can be from the centre, so that it can never outweigh an
+                // extra bin.
+                let worst_offset = bins
+                    .iter()
+                    .filter_map(|bin| {
+                        let [length, width, _] = bin.dims();
+                        let half_diagonal = length.hypot(width) / 2.0;
+                        bin.cog_offset()
+                            .filter(|_| half_diagonal > 0 as Dimension)
+                            .map(|offset| offset / half_diagonal)
+                    })
+                    .fold(0.0, f64::max);
+                bins.len() as f64 + worst_offset / 2.0
+            }
+            Objective::BillableWeight(rule) => rule.total_billable_weight(bins),
+        }
+    }
+}
+
+/**
+Packs the items with each of the strategies, and returns the strategy and bins of the packing with
+the lowest score under the objective. Ties are broken in favour of the strategy that comes first,
+so the result doesn't depend on the order the strategies finish in.
+
+With the `rayon` feature enabled, the strategies are packed in parallel.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+  use bin_packer_3d::strategy::{best_packing, Objective, Strategy};
+
+  let deck = Item::new("deck", [2.0, 8.0, 12.0]);
+  let die = Item::new("die", [8.0, 8.0, 8.0]);
+  let items = vec![deck, deck, die, deck, deck];
+
+  let (strategy, bins) =
+      best_packing(Bin::new([8.0, 8.0, 12.0]), &items, &Strategy::all(), Objective::FewestBins)
+          .unwrap();
+  assert_eq!(strategy, Strategy::default());
+  assert_eq!(bins.len(), 2);
+```
+**/
+pub fn best_packing<'a, T: Clone + Send + Sync>(
+    bin: Bin<'a, T>,
+    items: &[Item<'a, T>],
+    strategies: &[Strategy],
+    objective: Objective,
+) -> Result<(Strategy, Vec<Bin<'a, T>>)> {
+    if strategies.is_empty() {
+        let strategy = Strategy::default();
+        return pack_bins_with_strategy(bin, items, &strategy).map(|bins| (strategy, bins));
+    }
+
+    let pack = |strategy: &Strategy| {
+        pack_bins_with_strategy(bin.clone_as_empty_bin(), items, strategy)
+            .map(|bins| (objective.score(&bins), *strategy, bins))
+    };
+
+    #[cfg(feature = "rayon")]
+    let packings = strategies.par_iter().map(pack).collect::<Vec<_>>();
+    #[cfg(not(feature = "rayon"))]
+    let packings = strategies.iter().map(pack).collect::<Vec<_>>();
+
+    let mut best: Option<(f64, Strategy, Vec<Bin<'a, T>>)> = None;
+    let mut first_error = None;
+    for packing in packings {
+        match packing {
+            Ok(packing) => {
+                if best.as_ref().is_none_or(|(score, _, _)| packing.0 < *score) {
+                    best = Some(packing);
+                }
+            }
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+
+    match best {
+        Some((_, strategy, bins)) => Ok((strategy, bins)),
+        None => Err(first_error.expect("Invalid state - every strategy either packs or fails.")),
+    }
+}
@@ -0,0 +1,163 @@
+use crate::bin::Bin;
+use crate::block::Dimension;
+use crate::error::{Error, Result};
+use crate::item::Item;
+use crate::packing_algorithm::pack_bins_with_strategy;
+use crate::placement::EPSILON;
+use crate::shipping::CarrierRule;
+use crate::strategy::{Algorithm, Strategy};
+
+/// The number of times the search halves the range of lengths it's looking in.
+const SEARCH_STEPS: usize = 32;
+
+/// Represents the result of strip packing, where every item is packed into a single bin.
+#[derive(Clone, Debug)]
+pub struct StripPacking<'a, T = ()> {
+    /// The packed bin, which has been shortened along the open axis to the length that's needed.
+    pub bin: Bin<'a, T>,
+    /// The length needed along the open axis to pack every item.
+    pub length: Dimension,
+}
+
+/**
+Packs every item into a single bin where the two sides other than the open axis are fixed, and
+the open axis is as long as it needs to be, such as a flatbed trailer or a cut-to-size box. The
+axes are numbered in the same order as the bin's length, width and height, and the bin's length
+along the open axis is ignored.
+
+Searches for the shortest length along the open axis that all of the items can be packed into, and
+returns the packing along with the length it needs. Returns an `InvalidAxis` error if the open axis
+isn't 0, 1 or 2.
+
+```rust
+  use bin_packer_3d::bin::Bin;
+  use bin_packer_3d::item::Item;
+  use bin_packer_3d::strip::pack_strip;
+
+  let crate_ = Item::new("crate", [1.0, 1.0, 1.0]);
+  let trailer = Bin::new([0.0, 2.0, 2.0]);
+
+  let packing = pack_strip(trailer, 0, &[crate_.with_quantity(10)]).unwrap();
+  assert_eq!(packing.length, 3.0);
+  assert_eq!(packing.bin.dims(), [3.0, 2.0, 2.0]);
+  assert_eq!(packing.bin.items.len(), 10);
+```
+**/
+pub fn pack_strip<'a, T: Clone>(
+    bin: Bin<'a, T>,
+    open_axis: usize,
+    items: &[Item<'a, T>],
+) -> Result<StripPacking<'a, T>> {
+    if open_axis >= 3 {
+        return Err(Error::InvalidAxis(format!(
+            "The open axis is {}, but it must be 0, 1 or 2.",
+            open_axis
+        )));
+    }
+
+    // Each sort key fills a single bin differently, so every one of them is tried at each length.
+
+    let strategies = Strategy::all()
+        .into_iter()
+        .filter(|strategy| strategy.algorithm == Algorithm::OneBinAtATime)
+        .collect::<Vec<Strategy>>();
+
+    // Carrier limits on the bin's size only apply to the final length, so only the weight limit
+    // is kept while searching.
+
+    let carrier_rules = bin.carrier_rules().to_vec();
+    let weight_rules = carrier_rules
+        .iter()
+        .filter(|rule| matches!(rule, CarrierRule::MaxWeight(_)))
+        .copied()
+        .collect::<Vec<CarrierRule>>();
+    let bin = bin.with_carrier_rules(&weight_rules);
+
+    let cross_section = (0..3)
+        .filter(|&axis| axis != open_axis)
+        .map(|axis| bin.dims()[axis])
+        .product::<Dimension>();
+
+    // Lining every item up along the open axis, end to end, leaves enough room for every item that
+    // fits across the other two sides, although the greedy packing might not find that layout.
+
+    let mut upper = 2.0 * bin.wall_clearance()
+        + items
+            .iter()
+            .map(|item| (item.padded_block().dims[2] + bin.gap()) * item.quantity as Dimension)
+            .sum::<Dimension>();
+    let mut lower = items
+        .iter()
+        .map(|item| item.block.volume() * item.quantity as Dimension)
+        .sum::<Dimension>()
+        / cross_section;
+
+    let pack = |length: Dimension| -> Result<Option<Bin<'a, T>>> {
+        let mut shortest: Option<Bin<'a, T>> = None;
+        for strategy in strategies.iter() {
+            let mut bins =
+                pack_bins_with_strategy(bin.with_length(open_axis, length), items, strategy)?;
+            if bins.len() == 1 {
+                let packed = bins
+                    .pop()
+                    .expect("Invalid state - there's exactly one bin.");
+                if shortest.as_ref().is_none_or(|shortest| {
+                    packed.used_length(open_axis) < shortest.used_length(open_axis)
+                }) {
+                    shortest = Some(packed);
+                }
+            }
+        }
+        Ok(shortest)
+    };
+
+    let mut best = match pack(upper)? {
+        Some(packed) => packed,
+        None if items.iter().all(|item| item.quantity == 0) => {
+            bin.with_length(open_axis, 0 as Dimension)
+        }
+        None => {
+            return Err(Error::AllItemsMustFit(
+                "All items must fit into a single bin.".to_string(),
+            ))
+        }
+    };
+    let mut best_length = best.used_length(open_axis);
+    upper = upper.min(best_length);
+
+    // Greedy packing doesn't always get better with more room, so the search keeps the shortest
+    // packing it finds rather than the last one.
+
+    for _ in 0..SEARCH_STEPS {
+        if upper - lower <= bin.tolerance().max(EPSILON) {
+            break;
+        }
+        let length = (lower + upper) / 2.0;
+        match pack(length)? {
+            Some(packed) => {
+                let used = packed.used_length(open_axis);
+                if used < best_length {
+                    best_length = used;
+                    best = packed;
+                }
+                upper = length.min(used);
+            }
+            None => lower = length,
+        }
+    }
+
+    let bin = best
+        .resized(open_axis, best_length)
+        .with_carrier_rules(&carrier_rules);
+    if let Some(rule) = bin.broken_carrier_rule() {
+        return Err(Error::CarrierRuleViolated(format!(
+            "The packed bin breaks the carrier's {}.",
+            rule
+        )));
+    }
+
+    Ok(StripPacking {
+        bin,
+        length: best_length,
+    })
+}
@@ -1,5 +1,5 @@
 use crate::bin::Bin;
-use crate::block::{Block, Dimension};
+use crate::block::{Block, Dimension, Space};
 use crate::error::Result;
 use crate::item::Item;
 
@@ -143,4 +143,21 @@ mod block {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_space_without() -> Result<()> {
+        // cutting a box out of the corner of a space leaves the space beside it at full height, and
+        // the space above it
+        let space = Space::at([0.0, 0.0, 0.0], [4.0, 2.0, 2.0]);
+        assert_eq!(
+            space.without([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]),
+            vec![
+                Space::at([2.0, 0.0, 0.0], [2.0, 2.0, 2.0]),
+                Space::at([0.0, 0.0, 1.0], [2.0, 2.0, 1.0])
+            ]
+        );
+        // a box that doesn't overlap the space leaves it unchanged
+        assert_eq!(space.without([4.0, 0.0, 0.0], [1.0, 1.0, 1.0]), vec![space]);
+        Ok(())
+    }
 }
@@ -0,0 +1,384 @@
+use crate::bin::Bin;
+use crate::block::Dimension;
+use crate::grouping::Grouping;
+use crate::hazmat::Segregation;
+use crate::placement::{Placement, EPSILON, VERTICAL_AXIS};
+use crate::shipping::CarrierRule;
+
+/// Represents a way in which a packed bin breaks one of its constraints. Items are referred to by
+/// their index in the bin's `items`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Violation {
+    /// The item hasn't got a placement, because the bin has fewer `placements` than `items`. Only
+    /// the constraints that don't depend on where items are can be checked for it.
+    MissingPlacement {
+        /// The index of the item.
+        item: usize,
+    },
+
+    /// The placement hasn't got an item, because the bin has more `placements` than `items`.
+    MissingItem {
+        /// The index of the placement.
+        placement: usize,
+    },
+
+    /// The item, including its padding, extends past the walls of the bin or into the bin's wall
+    /// clearance.
+    OutOfBounds {
+        /// The index of the item.
+        item: usize,
+    },
+
+    /// The two items, including their padding, take up some of the same space, or are closer
+    /// together than the bin's gap.
+    Overlap {
+        /// The index of the first item.
+        first: usize,
+        /// The index of the second item.
+        second: usize,
+    },
+
+    /// The item, including its padding, takes up some of the space of one of the bin's
+    /// obstructions, or is closer to it than the bin's gap.
+    Obstructed {
+        /// The index of the item.
+        item: usize,
+    },
+
+    /// More weight rests on the item than its `max_load`.
+    Overloaded {
+        /// The index of the item.
+        item: usize,
+    },
+
+    /// More items are stacked on the item than its `max_stack`.
+    StackedTooHigh {
+        /// The index of the item.
+        item: usize,
+    },
+
+    /// Less of the item's base is supported than the bin's minimum support.
+    Unsupported {
+        /// The index of the item.
+        item: usize,
+    },
+
+    /// An item for a later delivery stop has to be moved to unload an item for an earlier stop.
+    BlocksUnloading {
+        /// The index of the item that's in the way.
+        blocking: usize,
+        /// The index of the item that can't be unloaded.
+        blocked: usize,
+    },
+
+    /// The bin breaks one of the carrier rules attached to it.
+    BreaksCarrierRule {
+        /// The rule that's broken.
+        rule: CarrierRule,
+    },
+
+    /// The item, including its padding, isn't entirely inside of a compartment of the zone it
+    /// requires, or crosses one of the bin's dividers.
+    OutsideCompartment {
+        /// The index of the item.
+        item: usize,
+    },
+
+    /// The two items share the bin, although one of the bin's groupings says that they must be
+    /// kept apart.
+    SharesBin {
+        /// The index of the first item.
+        first: usize,
+        /// The index of the second item.
+        second: usize,
+    },
+
+    /// The two items' hazmat classes must be kept apart, but they're in the same bin or closer
+    /// together than the bin's segregation table allows.
+    Segregation {
+        /// The index of the first item.
+        first: usize,
+        /// The index of the second item.
+        second: usize,
+    },
+
+    /// The item must be in the same bin as the other items with one of its tags, but some of them
+    /// are in other bins. This is only found when checking every bin with `verify_bins`.
+    SplitFromGroup {
+        /// The index of the item.
+        item: usize,
+    },
+}
+
+/**
+Checks the items packed into the bin against the bin's dimensions and every constraint that's
+been set on the bin or its items, and returns each violation that's found. Each item's padding,
+the gap between items and the bin's wall clearance are all taken into account. A bin packed by this
+crate never has any violations, but bins can also be built or edited by hand.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::item::Item;
+    use bin_packer_3d::placement::Placement;
+    use bin_packer_3d::verify::{verify, Violation};
+
+    let mut bin = Bin::new([4.0, 4.0, 4.0]);
+    bin.try_packing(Item::new("item1", [4.0, 4.0, 2.0]));
+    assert_eq!(verify(&bin), vec![]);
+
+    bin.items.push(Item::new("item2", [4.0, 4.0, 2.0]));
+    bin.placements.push(Placement { origin: [0.0, 0.0, 1.0], dims: [4.0, 4.0, 2.0] });
+    assert_eq!(verify(&bin), vec![Violation::Overlap { first: 0, second: 1 }]);
+```
+**/
+pub fn verify<T>(bin: &Bin<'_, T>) -> Vec<Violation> {
+    let placements = bin.padded_placements();
+    let tolerance = bin.tolerance().max(EPSILON);
+    let mut violations = Vec::new();
+
+    for item in bin.placements.len()..bin.items.len() {
+        violations.push(Violation::MissingPlacement { item });
+    }
+    for placement in bin.items.len()..bin.placements.len() {
+        violations.push(Violation::MissingItem { placement });
+    }
+
+    let dims = bin.dims();
+    for (item, placement) in placements.iter().enumerate() {
+        let inside = (0..3).all(|axis| {
+            let clearance = if axis == VERTICAL_AXIS {
+                0 as Dimension
+            } else {
+                bin.wall_clearance()
+            };
+            placement.origin[axis] + tolerance >= clearance
+                && placement.origin[axis] + placement.dims[axis]
+                    <= dims[axis] - clearance + tolerance
+        });
+        if !inside {
+            violations.push(Violation::OutOfBounds { item });
+        }
+    }
+
+    if !bin.compartments().is_empty() || bin.items.iter().any(|item| item.zone.is_some()) {
+        for (item, (packed, placement)) in bin.items.iter().zip(placements.iter()).enumerate() {
+            let inside = bin.compartments().iter().any(|compartment| {
+                compartment.accepts(packed.zone) && compartment.contains(placement, tolerance)
+            });
+            if !inside {
+                violations.push(Violation::OutsideCompartment { item });
+            }
+        }
+    }
+
+    // Widening each item by half of the gap, beside it, means that items which are closer
+    // together than the gap overlap. Items in different compartments are kept apart by the
+    // divider between them, so they can be packed against either side of it without a gap.
+
+    let spaced = placements
+        .iter()
+        .map(|placement| widen(placement, bin.gap() / 2.0))
+        .collect::<Vec<Placement>>();
+    let compartment_of = placements
+        .iter()
+        .map(|placement| {
+            bin.compartments()
+                .iter()
+                .position(|compartment| compartment.contains(placement, tolerance))
+        })
+        .collect::<Vec<Option<usize>>>();
+    for (first, first_placement) in spaced.iter().enumerate() {
+        for (second, second_placement) in spaced.iter().enumerate().skip(first + 1) {
+            let divided = matches!(
+                (compartment_of[first], compartment_of[second]),
+                (Some(a), Some(b)) if a != b
+            );
+            let overlap = if divided {
+                overlaps(&placements[first], &placements[second], tolerance)
+            } else {
+                overlaps(first_placement, second_placement, tolerance)
+            };
+            if overlap {
+                violations.push(Violation::Overlap { first, second });
+            }
+        }
+    }
+
+    let obstructions = bin
+        .obstructions()
+        .iter()
+        .map(|obstruction| widen(obstruction, bin.gap() / 2.0))
+        .collect::<Vec<Placement>>();
+    for (item, placement) in spaced.iter().enumerate() {
+        if obstructions
+            .iter()
+            .any(|obstruction| overlaps(placement, obstruction, tolerance))
+        {
+            violations.push(Violation::Obstructed { item });
+        }
+    }
+
+    let loads = bin.loads();
+    let stacked = bin.stacked_counts();
+    for (item, (packed, (load, stacked))) in bin
+        .items
+        .iter()
+        .zip(loads.iter().zip(stacked.iter()))
+        .enumerate()
+    {
+        if packed
+            .max_load
+            .is_some_and(|max_load| *load > max_load + EPSILON)
+        {
+            violations.push(Violation::Overloaded { item });
+        }
+        if packed
+            .max_stack
+            .is_some_and(|max_stack| *stacked > max_stack)
+        {
+            violations.push(Violation::StackedTooHigh { item });
+        }
+    }
+
+    if bin.min_support() > 0.0 {
+        for (item, ratio) in bin.support_ratios().into_iter().enumerate() {
+            if ratio + EPSILON < bin.min_support() {
+                violations.push(Violation::Unsupported { item });
+            }
+        }
+    }
+
+    if let Some(loading_axis) = bin.loading_axis().filter(|&axis| axis < 3) {
+        let stops = bin
+            .items
+            .iter()
+            .zip(placements.iter())
+            .enumerate()
+            .filter_map(|(index, (item, placement))| {
+                item.delivery_stop.map(|stop| (index, stop, placement))
+            })
+            .collect::<Vec<_>>();
+        for &(blocked, blocked_stop, blocked_placement) in stops.iter() {
+            for &(blocking, blocking_stop, blocking_placement) in stops.iter() {
+                if blocking_stop > blocked_stop
+                    && blocking_placement.blocks_unloading(
+                        blocked_placement,
+                        loading_axis,
+                        bin.tolerance(),
+                    )
+                {
+                    violations.push(Violation::BlocksUnloading { blocking, blocked });
+                }
+            }
+        }
+    }
+
+    for rule in bin.carrier_rules() {
+        if !rule.is_met_by(bin) {
+            violations.push(Violation::BreaksCarrierRule { rule: *rule });
+        }
+    }
+
+    for (first, first_item) in bin.items.iter().enumerate() {
+        for (second, second_item) in bin.items.iter().enumerate().skip(first + 1) {
+            if bin
+                .groupings()
+                .iter()
+                .any(|grouping| grouping.separates(first_item, second_item))
+            {
+                violations.push(Violation::SharesBin { first, second });
+            }
+
+            // Keeping two items out of the same bin doesn't depend on where they are, so it's
+            // checked even when one of them hasn't got a placement:
+
+            let segregated = match (bin.placements.get(first), bin.placements.get(second)) {
+                (Some(first_placement), Some(second_placement)) => bin.segregation().is_met_by(
+                    (first_item, first_placement),
+                    (second_item, second_placement),
+                    bin.tolerance(),
+                ),
+                _ => {
+                    bin.segregation().between(first_item, second_item)
+                        != Some(Segregation::DifferentBin)
+                }
+            };
+            if !segregated {
+                violations.push(Violation::Segregation { first, second });
+            }
+        }
+    }
+
+    violations
+}
+
+/**
+Checks every one of the packed bins with `verify`, and also checks that the items of each
+`SameBin` grouping haven't been split across bins. Returns each violation along with the index of
+the bin it's in. When a group is split, the items outside of the first bin that holds the group
+are reported.
+
+```rust
+    use bin_packer_3d::bin::Bin;
+    use bin_packer_3d::grouping::Grouping;
+    use bin_packer_3d::item::Item;
+    use bin_packer_3d::verify::{verify_bins, Violation};
+
+    let part = Item::new("part", [2.0, 2.0, 2.0]).with_tags(&["kit"]);
+    let bin = Bin::new([2.0, 2.0, 2.0]).with_groupings(&[Grouping::SameBin("kit")]);
+    let mut bins = vec![bin.clone_as_empty_bin(), bin];
+    bins[0].try_packing(part);
+    bins[1].try_packing(part);
+    assert_eq!(verify_bins(&bins), vec![(1, Violation::SplitFromGroup { item: 0 })]);
+```
+**/
+pub fn verify_bins<T>(bins: &[Bin<'_, T>]) -> Vec<(usize, Violation)> {
+    let mut violations = bins
+        .iter()
+        .enumerate()
+        .flat_map(|(index, bin)| {
+            verify(bin)
+                .into_iter()
+                .map(move |violation| (index, violation))
+        })
+        .collect::<Vec<(usize, Violation)>>();
+
+    let mut kit_tags = Vec::new();
+    for grouping in bins.iter().flat_map(|bin| bin.groupings()) {
+        if let Grouping::SameBin(tag) = grouping {
+            if !kit_tags.contains(tag) {
+                kit_tags.push(*tag);
+            }
+        }
+    }
+
+    for tag in kit_tags {
+        let holds_tag = |bin: &Bin<'_, T>| bin.items.iter().any(|item| item.has_tag(tag));
+        if let Some(first_bin) = bins.iter().position(holds_tag) {
+            for (index, bin) in bins.iter().enumerate().skip(first_bin + 1) {
+                for (item, packed) in bin.items.iter().enumerate() {
+                    if packed.has_tag(tag) {
+                        violations.push((index, Violation::SplitFromGroup { item }));
+                    }
+                }
+            }
+        }
+    }
+
+    violations
+}
+
+/// Returns the placement, widened by the margin on every side other than its top and bottom.
+fn widen(placement: &Placement, margin: Dimension) -> Placement {
+    let mut widened = *placement;
+    for axis in (0..3).filter(|&axis| axis != VERTICAL_AXIS) {
+        widened.origin[axis] -= margin;
+        widened.dims[axis] += 2.0 * margin;
+    }
+    widened
+}
+
+/// Returns whether or not the two placements share more than a sliver of space.
+fn overlaps(first: &Placement, second: &Placement, tolerance: Dimension) -> bool {
+    (0..3).all(|axis| first.overlap_along(second, axis) > tolerance)
+}
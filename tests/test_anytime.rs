@@ -0,0 +1,98 @@
+use bin_packer_3d::anytime::{pack_anytime, Budget, CancellationToken};
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::Result;
+use bin_packer_3d::item::Item;
+use bin_packer_3d::strategy::{Objective, SortKey, Strategy};
+use std::time::{Duration, Instant};
+
+/// test anytime API
+
+#[test]
+fn test_unlimited_budget_tries_every_strategy() -> Result<()> {
+    let packing = pack_anytime(
+        Bin::new([6, 4, 9]),
+        &items(),
+        &Strategy::all(),
+        Objective::FewestBins,
+        &Budget::default(),
+    )?;
+    assert!(!packing.stopped_early);
+    assert_eq!(packing.bins.len(), 1);
+    assert_eq!(packing.strategy.sort_key, SortKey::Volume);
+    Ok(())
+}
+
+#[test]
+fn test_cancelled_returns_first_answer() -> Result<()> {
+    let token = CancellationToken::new();
+    token.cancel();
+    let packing = pack_anytime(
+        Bin::new([6, 4, 9]),
+        &items(),
+        &Strategy::all(),
+        Objective::FewestBins,
+        &Budget::default().with_cancellation(token),
+    )?;
+    assert!(packing.stopped_early);
+    assert_eq!(packing.strategy, Strategy::default());
+    assert_eq!(packing.bins.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_passed_deadline_returns_first_answer() -> Result<()> {
+    let packing = pack_anytime(
+        Bin::new([6, 4, 9]),
+        &items(),
+        &Strategy::all(),
+        Objective::FewestBins,
+        &Budget::default().with_deadline(Instant::now()),
+    )?;
+    assert!(packing.stopped_early);
+    assert_eq!(packing.bins.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_only_default_strategy_is_not_stopped_early() -> Result<()> {
+    let packing = pack_anytime(
+        Bin::new([6, 4, 9]),
+        &items(),
+        &[Strategy::default()],
+        Objective::FewestBins,
+        &Budget::default().with_time_limit(Duration::from_secs(0)),
+    )?;
+    assert!(!packing.stopped_early);
+    Ok(())
+}
+
+#[test]
+fn test_failing_strategy_is_skipped() -> Result<()> {
+    // the item only fits within the bin's tolerance, so the strategy without a tolerance fails
+
+    let strict = Strategy {
+        tolerance: Some(0.0),
+        ..Default::default()
+    };
+    let packing = pack_anytime(
+        Bin::new([10.0, 4.0, 4.0]).with_tolerance(0.1),
+        &[Item::new("item1", [10.05, 4.0, 4.0])],
+        &[strict],
+        Objective::FewestBins,
+        &Budget::default(),
+    )?;
+    assert!(!packing.stopped_early);
+    assert_eq!(packing.bins.len(), 1);
+    assert_eq!(packing.strategy, Strategy::default());
+    Ok(())
+}
+
+fn items() -> Vec<Item<'static>> {
+    // the default strategy needs 2 bins for these items, but sorting by volume only needs 1
+
+    vec![
+        Item::new("item1", [2, 4, 6]),
+        Item::new("item2", [2, 3, 5]),
+        Item::new("item3", [2, 3, 8]),
+    ]
+}
@@ -0,0 +1,82 @@
+use bin_packer_3d::assortment::{choose_assortment, BoxSize};
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::Result;
+use bin_packer_3d::item::Item;
+use bin_packer_3d::shipping::DimWeight;
+
+/// test assortment API
+
+#[test]
+fn test_minimise_shipped_volume() -> Result<()> {
+    let candidates = vec![
+        BoxSize::new(Bin::new([1, 1, 1])),
+        BoxSize::new(Bin::new([1, 1, 2])),
+        BoxSize::new(Bin::new([2, 2, 2])),
+        BoxSize::new(Bin::new([2, 2, 3])),
+    ];
+
+    let assortment = choose_assortment(&orders(), &candidates, 1);
+    assert_eq!(assortment.chosen, vec![2]);
+    assert_eq!(assortment.total_cost, 8.0 * 6.0);
+    assert!(assortment.unserved_orders.is_empty());
+
+    let assortment = choose_assortment(&orders(), &candidates, 2);
+    assert_eq!(assortment.chosen, vec![0, 2]);
+    assert_eq!(assortment.total_cost, 1.0 + 2.0 + 8.0 + 8.0 + 16.0);
+    Ok(())
+}
+
+#[test]
+fn test_minimise_cost() -> Result<()> {
+    // test that a flat rate makes the larger box the better choice
+
+    let candidates = vec![
+        BoxSize::new(Bin::new([1, 1, 2])).with_cost(5.0),
+        BoxSize::new(Bin::new([2, 2, 3])).with_cost(6.0),
+    ];
+    let assortment = choose_assortment(&orders(), &candidates, 1);
+    assert_eq!(assortment.chosen, vec![1]);
+    assert_eq!(assortment.total_cost, 30.0);
+    Ok(())
+}
+
+#[test]
+fn test_minimise_billable_weight() -> Result<()> {
+    // test that rounding each box's billable weight up makes one box cheaper than two
+
+    let rule = DimWeight::new(1.0).with_weight_rounding(1.0);
+    let candidates = vec![
+        BoxSize::new(Bin::new([1, 1, 1])).with_cost(rule),
+        BoxSize::new(Bin::new([1, 1, 2])).with_cost(rule),
+    ];
+    let heavy = Item::new("heavy", [1, 1, 1]).with_weight(1.5);
+    let assortment = choose_assortment(&[vec![heavy.with_quantity(2)]], &candidates, 1);
+    assert_eq!(assortment.chosen, vec![1]);
+    assert_eq!(assortment.total_cost, 3.0);
+    Ok(())
+}
+
+#[test]
+fn test_unserved_orders() -> Result<()> {
+    let candidates = vec![
+        BoxSize::new(Bin::new([1, 1, 1])),
+        BoxSize::new(Bin::new([1, 1, 2])),
+    ];
+    let assortment = choose_assortment(&orders(), &candidates, 5);
+    assert_eq!(assortment.chosen, vec![0, 1]);
+    assert_eq!(assortment.unserved_orders, vec![3, 4]);
+    assert_eq!(assortment.total_cost, 1.0 + 2.0 + 8.0);
+    Ok(())
+}
+
+fn orders() -> Vec<Vec<Item<'static>>> {
+    let small = Item::new("small", [1, 1, 1]);
+    let large = Item::new("large", [2, 2, 2]);
+    vec![
+        vec![small],
+        vec![small, small],
+        vec![small.with_quantity(8)],
+        vec![large],
+        vec![large, small],
+    ]
+}
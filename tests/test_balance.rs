@@ -0,0 +1,74 @@
+use bin_packer_3d::balance::{pack_balanced, FillMeasure};
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::pack_bins;
+use bin_packer_3d::verify::verify;
+
+/// test balanced packing API
+
+#[test]
+fn test_spreads_weight_evenly() -> Result<()> {
+    let heavy = Item::new("heavy", [1, 1, 1]).with_weight(5.0);
+    let medium = Item::new("medium", [1, 1, 1]).with_weight(3.0);
+    let light = Item::new("light", [1, 1, 1]).with_weight(2.0);
+    let items = [heavy, heavy, medium, medium, light, light];
+    let bins = pack_balanced(Bin::new([4, 4, 4]), &items, 2, FillMeasure::Weight)?;
+    assert_eq!(bins.len(), 2);
+    assert_eq!(bins[0].total_weight(), 10.0);
+    assert_eq!(bins[1].total_weight(), 10.0);
+    assert!(bins.iter().all(|bin| verify(bin).is_empty()));
+
+    // First fit packing would put every item into the first bin.
+    assert_eq!(pack_bins(Bin::new([4, 4, 4]), &items)?.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_spreads_volume_evenly() -> Result<()> {
+    let big = Item::new("big", [2, 2, 2]);
+    let small = Item::new("small", [1, 1, 1]);
+    let items = [big, small.with_quantity(8)];
+    let bins = pack_balanced(Bin::new([4, 4, 4]), &items, 2, FillMeasure::Volume)?;
+    let fills = bins
+        .iter()
+        .map(|bin| FillMeasure::Volume.fill(bin))
+        .collect::<Vec<f64>>();
+    assert_eq!(fills, vec![8.0, 8.0]);
+    Ok(())
+}
+
+#[test]
+fn test_returns_empty_bins() -> Result<()> {
+    let item = Item::new("item", [1, 1, 1]);
+    let bins = pack_balanced(Bin::new([4, 4, 4]), &[item], 3, FillMeasure::Volume)?;
+    assert_eq!(bins.len(), 3);
+    assert_eq!(bins.iter().filter(|bin| bin.items.is_empty()).count(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_not_enough_bins() {
+    let item = Item::new("item", [2, 2, 2]);
+    let err = pack_balanced(
+        Bin::new([2, 2, 4]),
+        &[item.with_quantity(5)],
+        2,
+        FillMeasure::Volume,
+    );
+    assert_eq!(
+        err.map(|bins| bins.len()),
+        Err(Error::NotEnoughBins(
+            "The items can't be packed into 2 bins.".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_item_too_big_for_bins() {
+    let item = Item::new("item", [5, 1, 1]);
+    assert!(matches!(
+        pack_balanced(Bin::new([4, 4, 4]), &[item], 2, FillMeasure::Weight),
+        Err(Error::AllItemsMustFit(_))
+    ));
+}
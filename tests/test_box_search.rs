@@ -0,0 +1,56 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::box_search::{smallest_box, BoxLimits};
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::shipping::CarrierRule;
+use bin_packer_3d::verify::verify;
+
+/// test box search API
+
+#[test]
+fn test_smallest_box_for_identical_items() -> Result<()> {
+    let item = Item::new("item", [1, 2, 3]);
+    let found = smallest_box(
+        Bin::new([0, 0, 0]),
+        &[item.with_quantity(4)],
+        &BoxLimits::default(),
+    )?;
+    assert_eq!(found.dims.iter().product::<f64>(), 24.0);
+    assert_eq!(found.bin.dims(), found.dims);
+    assert_eq!(found.bin.items.len(), 4);
+    assert!(verify(&found.bin).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_box_limits() -> Result<()> {
+    // test that every side is a multiple of the increment, and within the limits
+
+    let item = Item::new("item", [1.5, 2.5, 3.5]);
+    let limits = BoxLimits::default().with_min_side(3.0).with_increment(1.0);
+    let found = smallest_box(Bin::new([0, 0, 0]), &[item, item], &limits)?;
+    assert!(found
+        .dims
+        .iter()
+        .all(|&side| side >= 3.0 && side.fract() == 0.0));
+    assert_eq!(found.dims.iter().product::<f64>(), 36.0);
+    assert!(verify(&found.bin).is_empty());
+
+    let limits = BoxLimits::default().with_max_side(3.0);
+    assert_eq!(
+        smallest_box(Bin::new([0, 0, 0]), &[item], &limits).map(|found| found.dims),
+        Err(Error::NoBoxFound(
+            "No box within the limits can fit all of the items.".to_string()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_smallest_box_meets_carrier_rules() -> Result<()> {
+    let item = Item::new("item", [1, 1, 1]);
+    let bin = Bin::new([0, 0, 0]).with_carrier_rules(&[CarrierRule::MaxSide(2.0)]);
+    let found = smallest_box(bin, &[item.with_quantity(8)], &BoxLimits::default())?;
+    assert_eq!(found.dims, [2.0, 2.0, 2.0]);
+    Ok(())
+}
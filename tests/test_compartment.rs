@@ -0,0 +1,118 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::compartment::Compartment;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::pack_bins;
+use bin_packer_3d::placement::Placement;
+use bin_packer_3d::verify::{verify, Violation};
+
+/// test compartment API
+
+#[test]
+fn test_items_go_into_their_zone() -> Result<()> {
+    let peas = Item::new("peas", [1, 1, 1]).with_zone("frozen");
+    let milk = Item::new("milk", [1, 1, 1]).with_zone("chilled");
+    let pasta = Item::new("pasta", [1, 1, 1]).with_zone("ambient");
+    let bins = pack_bins(
+        grocery_tote(),
+        &[
+            peas.with_quantity(4),
+            milk.with_quantity(4),
+            pasta.with_quantity(8),
+        ],
+    )?;
+    assert_eq!(bins.len(), 1);
+    assert_eq!(bins[0].items.len(), 16);
+    assert!(verify(&bins[0]).is_empty());
+    for (item, placement) in bins[0].items.iter().zip(bins[0].placements.iter()) {
+        let compartment = bins[0]
+            .compartments()
+            .iter()
+            .find(|compartment| compartment.contains(placement, 0.0))
+            .unwrap();
+        assert_eq!(compartment.zone, item.zone);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_full_zone_opens_a_new_bin() -> Result<()> {
+    let peas = Item::new("peas", [1, 1, 1]).with_zone("frozen");
+    let bins = pack_bins(grocery_tote(), &[peas.with_quantity(5)])?;
+    assert_eq!(bins.len(), 2);
+    assert_eq!(bins[0].items.len(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_items_dont_cross_dividers() -> Result<()> {
+    let long = Item::new("long", [2, 1, 1]);
+    let bin = Bin::new([3, 1, 1]).with_compartments(&[
+        Compartment::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+        Compartment::new([1.0, 0.0, 0.0], [2.0, 1.0, 1.0]),
+    ]);
+    let bins = pack_bins(bin, &[long, long])?;
+    assert_eq!(bins.len(), 2);
+    assert_eq!(bins[0].placements[0].origin, [1.0, 0.0, 0.0]);
+    Ok(())
+}
+
+#[test]
+fn test_compartments_with_gap() -> Result<()> {
+    let item = Item::new("item", [1, 1, 1]);
+    let bin = Bin::new([4, 2, 1]).with_gap(1.0).with_compartments(&[
+        Compartment::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]),
+        Compartment::new([2.0, 0.0, 0.0], [2.0, 2.0, 1.0]),
+    ]);
+    let bins = pack_bins(bin, &[item.with_quantity(4)])?;
+    assert_eq!(bins.len(), 2);
+    assert!(bins.iter().all(|bin| verify(bin).is_empty()));
+    Ok(())
+}
+
+#[test]
+fn test_items_touch_a_divider_from_both_sides() -> Result<()> {
+    let item = Item::new("item", [2, 2, 1]);
+    let bin = Bin::new([4, 2, 1]).with_gap(1.0).with_compartments(&[
+        Compartment::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]),
+        Compartment::new([2.0, 0.0, 0.0], [2.0, 2.0, 1.0]),
+    ]);
+    let bins = pack_bins(bin, &[item.with_quantity(2)])?;
+    assert_eq!(bins.len(), 1);
+    assert_eq!(bins[0].placements[0].origin, [0.0, 0.0, 0.0]);
+    assert_eq!(bins[0].placements[1].origin, [2.0, 0.0, 0.0]);
+    assert!(verify(&bins[0]).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_item_without_matching_zone() {
+    let ice = Item::new("ice", [1, 1, 1]).with_zone("deep-frozen");
+    assert!(matches!(
+        pack_bins(grocery_tote(), &[ice]),
+        Err(Error::AllItemsMustFit(_))
+    ));
+}
+
+#[test]
+fn test_verify_finds_items_in_the_wrong_zone() {
+    let mut bin = grocery_tote();
+    bin.items
+        .push(Item::new("peas", [1, 1, 1]).with_zone("frozen"));
+    bin.placements.push(Placement {
+        origin: [3.0, 0.0, 0.0],
+        dims: [1.0, 1.0, 1.0],
+    });
+    assert_eq!(
+        verify(&bin),
+        vec![Violation::OutsideCompartment { item: 0 }]
+    );
+}
+
+fn grocery_tote() -> Bin<'static> {
+    Bin::new([4, 2, 2]).with_compartments(&[
+        Compartment::new([0.0, 0.0, 0.0], [1.0, 2.0, 2.0]).with_zone("frozen"),
+        Compartment::new([1.0, 0.0, 0.0], [1.0, 2.0, 2.0]).with_zone("chilled"),
+        Compartment::new([2.0, 0.0, 0.0], [2.0, 2.0, 2.0]).with_zone("ambient"),
+    ])
+}
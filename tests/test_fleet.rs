@@ -0,0 +1,102 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::fleet::{pack_fleet, BinType};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::shipping::{CarrierRule, DimWeight};
+use bin_packer_3d::verify::verify;
+
+/// test fleet packing API
+
+#[test]
+fn test_respects_available_counts() -> Result<()> {
+    let pallet = Item::new("pallet", [4, 4, 4]);
+    let fleet = vec![BinType::new(Bin::new([8, 4, 4]), 2)];
+    let packing = pack_fleet(&fleet, &[pallet.with_quantity(5)])?;
+    assert_eq!(packing.bins.len(), 2);
+    assert_eq!(packing.unloaded.len(), 1);
+    assert_eq!(packing.total_cost, 256.0);
+    assert!(packing.bins.iter().all(|(_, bin)| verify(bin).is_empty()));
+    Ok(())
+}
+
+#[test]
+fn test_uses_cheapest_type_for_the_load() -> Result<()> {
+    let pallet = Item::new("pallet", [4, 4, 4]);
+    let fleet = vec![
+        BinType::new(Bin::new([16, 4, 4]), 3).with_cost(10.0),
+        BinType::new(Bin::new([4, 4, 4]), 3).with_cost(4.0),
+    ];
+    let packing = pack_fleet(&fleet, &[pallet.with_quantity(5)])?;
+    let types = packing
+        .bins
+        .iter()
+        .map(|(bin_type, _)| *bin_type)
+        .collect::<Vec<usize>>();
+    assert_eq!(types, vec![0, 1]);
+    assert_eq!(packing.total_cost, 14.0);
+    assert!(packing.unloaded.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_billable_weight_cost() -> Result<()> {
+    // test that rounding each bin's billable weight up makes one bin cheaper than two
+
+    let rule = DimWeight::new(1.0).with_weight_rounding(1.0);
+    let fleet = vec![
+        BinType::new(Bin::new([1, 1, 1]), 2).with_cost(rule),
+        BinType::new(Bin::new([1, 1, 2]), 1).with_cost(rule),
+    ];
+    let heavy = Item::new("heavy", [1, 1, 1]).with_weight(1.5);
+    let packing = pack_fleet(&fleet, &[heavy.with_quantity(2)])?;
+    assert_eq!(packing.bins.len(), 1);
+    assert_eq!(packing.bins[0].0, 1);
+    assert_eq!(packing.total_cost, 3.0);
+    Ok(())
+}
+
+#[test]
+fn test_items_without_volume_use_cheapest_type() -> Result<()> {
+    let sheet = Item::new("sheet", [4, 4, 0]);
+    let fleet = vec![
+        BinType::new(Bin::new([4, 4, 4]), 1).with_cost(10.0),
+        BinType::new(Bin::new([4, 4, 4]), 1).with_cost(4.0),
+    ];
+    let packing = pack_fleet(&fleet, &[sheet.with_quantity(3)])?;
+    assert_eq!(packing.bins.len(), 1);
+    assert_eq!(packing.bins[0].0, 1);
+    assert_eq!(packing.total_cost, 4.0);
+    Ok(())
+}
+
+#[test]
+fn test_reports_items_that_dont_fit_any_type() -> Result<()> {
+    let small = Item::new("small", [1, 1, 1]);
+    let huge = Item::new("huge", [20, 20, 20]);
+    let fleet = vec![BinType::new(Bin::new([4, 4, 4]), 1)];
+    let packing = pack_fleet(&fleet, &[small, huge])?;
+    assert_eq!(packing.bins.len(), 1);
+    assert_eq!(packing.unloaded.len(), 1);
+    assert_eq!(packing.unloaded[0].id, "huge");
+    Ok(())
+}
+
+#[test]
+fn test_empty_fleet() -> Result<()> {
+    let item = Item::new("item", [1, 1, 1]);
+    let packing = pack_fleet::<()>(&[], &[item.with_quantity(2)])?;
+    assert!(packing.bins.is_empty());
+    assert_eq!(packing.unloaded.len(), 2);
+    assert_eq!(packing.total_cost, 0.0);
+    Ok(())
+}
+
+#[test]
+fn test_bin_type_breaking_carrier_rule() {
+    let item = Item::new("item", [1, 1, 1]);
+    let bin = Bin::new([4, 4, 4]).with_carrier_rules(&[CarrierRule::MaxSide(2.0)]);
+    assert!(matches!(
+        pack_fleet(&[BinType::new(bin, 1)], &[item]),
+        Err(Error::CarrierRuleViolated(_))
+    ));
+}
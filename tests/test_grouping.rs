@@ -0,0 +1,150 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::fleet::{pack_fleet, BinType};
+use bin_packer_3d::grouping::Grouping;
+use bin_packer_3d::item::Item;
+use bin_packer_3d::knapsack::pack_knapsack;
+use bin_packer_3d::packing_algorithm::{pack_bins, pack_bins_with_strategy, pack_grouped};
+use bin_packer_3d::strategy::Strategy;
+use bin_packer_3d::verify::{verify, verify_bins, Violation};
+
+/// test grouping API
+
+#[test]
+fn test_kits_share_a_bin_with_every_strategy() -> Result<()> {
+    let filler = Item::new("filler", [2, 2, 3]);
+    let lamp = Item::new("lamp", [2, 2, 2]).with_tags(&["kit"]);
+    let bulb = Item::new("bulb", [2, 2, 1]).with_tags(&["kit"]);
+    let bin = Bin::new([2, 2, 4]).with_groupings(&[Grouping::SameBin("kit")]);
+    for strategy in Strategy::all() {
+        let bins = pack_bins_with_strategy(
+            bin.clone_as_empty_bin(),
+            &[filler, filler, lamp, bulb],
+            &strategy,
+        )?;
+        assert!(verify_bins(&bins).is_empty());
+        let kit_bins = bins
+            .iter()
+            .filter(|bin| bin.items.iter().any(|item| item.has_tag("kit")))
+            .count();
+        assert_eq!(kit_bins, 1);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_kits_share_a_bin_when_grouped() -> Result<()> {
+    let part = Item::new("part", [1, 1, 1]).with_tags(&["kit"]);
+    let other = Item::new("other", [1, 1, 1]);
+    let bin = Bin::new([2, 2, 2]).with_groupings(&[Grouping::SameBin("kit")]);
+    let packed = pack_grouped(bin, &[other.with_quantity(6), part.with_quantity(4)])?;
+    let kit_bins = packed
+        .iter()
+        .filter(|bin| bin.iter().any(|item| item.id == "part"))
+        .collect::<Vec<_>>();
+    assert_eq!(kit_bins.len(), 1);
+    assert_eq!(
+        kit_bins[0]
+            .iter()
+            .find(|item| item.id == "part")
+            .unwrap()
+            .quantity,
+        4
+    );
+    Ok(())
+}
+
+#[test]
+fn test_grouped_kits_keep_payloads() -> Result<()> {
+    let sku = Item::new("sku", [1, 1, 1]).with_tags(&["kit"]);
+    let bin = Bin::new([2, 2, 2])
+        .for_payload()
+        .with_groupings(&[Grouping::SameBin("kit")]);
+    let packed = pack_grouped(
+        bin,
+        &[
+            sku.with_data("order-A"),
+            sku.with_data("order-B").with_quantity(2),
+        ],
+    )?;
+    assert_eq!(
+        packed
+            .iter()
+            .map(|bin| bin.iter().map(|item| (item.data, item.quantity)).collect())
+            .collect::<Vec<Vec<(&str, usize)>>>(),
+        vec![vec![("order-A", 1), ("order-B", 2)]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_separated_items_never_share_a_bin() -> Result<()> {
+    let bleach = Item::new("bleach", [1, 1, 1]).with_tags(&["chemicals"]);
+    let bread = Item::new("bread", [1, 1, 1]).with_tags(&["food"]);
+    let bin = Bin::new([4, 4, 4]).with_groupings(&[Grouping::DifferentBin("chemicals", "food")]);
+    let bins = pack_bins(bin, &[bleach, bread, bleach, bread])?;
+    assert_eq!(bins.len(), 2);
+    assert!(verify_bins(&bins).is_empty());
+    for bin in bins.iter() {
+        assert!(bin.items.iter().all(|item| item.id == bin.items[0].id));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_kit_too_big_for_a_bin() {
+    let part = Item::new("part", [2, 2, 2]).with_tags(&["kit"]);
+    let bin = Bin::new([2, 2, 2]).with_groupings(&[Grouping::SameBin("kit")]);
+    assert_eq!(
+        pack_bins(bin, &[part, part]).map(|bins| bins.len()),
+        Err(Error::GroupingViolated(
+            "The items tagged `kit` can't all be packed into a single bin.".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_knapsack_packs_whole_kits() -> Result<()> {
+    let part = Item::new("part", [2, 2, 2])
+        .with_tags(&["kit"])
+        .with_value(100.0);
+    let other = Item::new("other", [2, 2, 2]).with_value(1.0);
+    let bin = Bin::new([2, 2, 4]).with_groupings(&[Grouping::SameBin("kit")]);
+    let knapsack = pack_knapsack(bin, &[part.with_quantity(3), other])?;
+    assert_eq!(knapsack.value(), 1.0);
+    assert_eq!(knapsack.rejected.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_fleet_keeps_kits_together() -> Result<()> {
+    let part = Item::new("part", [2, 2, 2]).with_tags(&["kit"]);
+    let other = Item::new("other", [2, 2, 2]);
+    let bin = Bin::new([2, 2, 4]).with_groupings(&[Grouping::SameBin("kit")]);
+    let packing = pack_fleet(&[BinType::new(bin, 2)], &[other, part, part])?;
+    assert!(packing.unloaded.is_empty());
+    let bins = packing
+        .bins
+        .into_iter()
+        .map(|(_, bin)| bin)
+        .collect::<Vec<Bin>>();
+    assert!(verify_bins(&bins).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_verify_finds_items_sharing_a_bin() {
+    let bleach = Item::new("bleach", [1, 1, 1]).with_tags(&["chemicals"]);
+    let bread = Item::new("bread", [1, 1, 1]).with_tags(&["food"]);
+    let mut bin = Bin::new([2, 2, 2]);
+    assert!(bin.try_packing(bleach).is_some());
+    assert!(bin.try_packing(bread).is_some());
+    let bin = bin.with_groupings(&[Grouping::DifferentBin("food", "chemicals")]);
+    assert_eq!(
+        verify(&bin),
+        vec![Violation::SharesBin {
+            first: 0,
+            second: 1
+        }]
+    );
+}
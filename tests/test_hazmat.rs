@@ -0,0 +1,148 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::hazmat::{Segregation, SegregationTable};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::pack_bins;
+use bin_packer_3d::placement::Placement;
+use bin_packer_3d::verify::{verify, Violation};
+
+/// test hazmat segregation API
+
+#[test]
+fn test_incompatible_classes_use_different_bins() -> Result<()> {
+    let fuel = Item::new("fuel", [1, 1, 1]).with_hazmat_class("3");
+    let oxidiser = Item::new("oxidiser", [1, 1, 1]).with_hazmat_class("5.1");
+    let table = SegregationTable::default().with_rule("5.1", "3", Segregation::DifferentBin);
+    let bins = pack_bins(
+        Bin::new([4, 4, 4]).with_segregation(table),
+        &[fuel, oxidiser, fuel],
+    )?;
+    assert_eq!(bins.len(), 2);
+    assert!(bins.iter().all(|bin| verify(bin).is_empty()));
+    Ok(())
+}
+
+#[test]
+fn test_min_distance_inside_a_bin() -> Result<()> {
+    let fuel = Item::new("fuel", [1, 1, 1]).with_hazmat_class("3");
+    let acid = Item::new("acid", [1, 1, 1]).with_hazmat_class("8");
+    let filler = Item::new("filler", [1, 1, 1]);
+    let table = SegregationTable::default().with_rule("3", "8", Segregation::MinDistance(2.0));
+    let bins = pack_bins(
+        Bin::new([4, 1, 1]).with_segregation(table),
+        &[fuel, filler, filler, acid],
+    )?;
+    assert_eq!(bins.len(), 1);
+    assert!(verify(&bins[0]).is_empty());
+    let fuel_at = bins[0].items.iter().position(|item| item.id == "fuel");
+    let acid_at = bins[0].items.iter().position(|item| item.id == "acid");
+    let distance =
+        bins[0].placements[fuel_at.unwrap()].distance_to(&bins[0].placements[acid_at.unwrap()]);
+    assert!(distance >= 2.0);
+    Ok(())
+}
+
+#[test]
+fn test_unlisted_classes_can_touch() -> Result<()> {
+    let fuel = Item::new("fuel", [1, 1, 1]).with_hazmat_class("3");
+    let gas = Item::new("gas", [1, 1, 1]).with_hazmat_class("2.2");
+    let table = SegregationTable::default().with_rule("3", "8", Segregation::DifferentBin);
+    let bins = pack_bins(Bin::new([2, 1, 1]).with_segregation(table), &[fuel, gas])?;
+    assert_eq!(bins.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_strictest_rule_applies() {
+    let table = SegregationTable::default()
+        .with_rule("3", "8", Segregation::MinDistance(2.0))
+        .with_rule("8", "3", Segregation::MinDistance(5.0));
+    assert_eq!(
+        table.segregation("3", "8"),
+        Some(Segregation::MinDistance(5.0))
+    );
+    let table = table.with_rule("3", "8", Segregation::DifferentBin);
+    assert_eq!(table.segregation("8", "3"), Some(Segregation::DifferentBin));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        SegregationTable::parse("3 8 distance x"),
+        Err(Error::InvalidSegregationTable(
+            "Line 1 has an invalid distance `x`.".to_string()
+        ))
+    );
+    assert_eq!(
+        SegregationTable::parse("\n3 8 nearby"),
+        Err(Error::InvalidSegregationTable(
+            "Line 2 has an unknown segregation `nearby`.".to_string()
+        ))
+    );
+    assert_eq!(
+        SegregationTable::parse("3 different-bin"),
+        Err(Error::InvalidSegregationTable(
+            "Line 1 needs two classes and a segregation.".to_string()
+        ))
+    );
+    assert_eq!(
+        SegregationTable::parse("3 5.1 different-bin extra"),
+        Err(Error::InvalidSegregationTable(
+            "Line 1 has an unexpected field `extra`.".to_string()
+        ))
+    );
+    assert_eq!(
+        SegregationTable::parse("3 8 distance 3.0 extra"),
+        Err(Error::InvalidSegregationTable(
+            "Line 1 has an unexpected field `extra`.".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_verify_finds_segregation_violations() {
+    let fuel = Item::new("fuel", [1, 1, 1]).with_hazmat_class("3");
+    let acid = Item::new("acid", [1, 1, 1]).with_hazmat_class("8");
+    let table = SegregationTable::default().with_rule("3", "8", Segregation::MinDistance(2.0));
+    let mut bin = Bin::new([4, 1, 1]).with_segregation(table);
+    bin.items = vec![fuel, acid];
+    bin.placements = vec![
+        Placement {
+            origin: [0.0, 0.0, 0.0],
+            dims: [1.0, 1.0, 1.0],
+        },
+        Placement {
+            origin: [2.0, 0.0, 0.0],
+            dims: [1.0, 1.0, 1.0],
+        },
+    ];
+    assert_eq!(
+        verify(&bin),
+        vec![Violation::Segregation {
+            first: 0,
+            second: 1
+        }]
+    );
+    bin.placements[1].origin = [3.0, 0.0, 0.0];
+    assert!(verify(&bin).is_empty());
+}
+
+#[test]
+fn test_verify_finds_different_bin_segregation_without_placements() {
+    let fuel = Item::new("fuel", [1, 1, 1]).with_hazmat_class("3");
+    let acid = Item::new("acid", [1, 1, 1]).with_hazmat_class("8");
+    let table = SegregationTable::default().with_rule("3", "8", Segregation::DifferentBin);
+    let mut bin = Bin::new([4, 1, 1]).with_segregation(table);
+    bin.try_packing(fuel);
+    bin.items.push(acid);
+    assert_eq!(
+        verify(&bin),
+        vec![
+            Violation::MissingPlacement { item: 1 },
+            Violation::Segregation {
+                first: 0,
+                second: 1
+            }
+        ]
+    );
+}
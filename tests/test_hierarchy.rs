@@ -0,0 +1,67 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::hierarchy::{pack_levels, skus, Contents, Level};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::verify::verify;
+
+/// test hierarchical packing API
+
+#[test]
+fn test_three_levels() -> Result<()> {
+    let mug = Item::new("mug", [1, 1, 1])
+        .with_weight(1.0)
+        .with_data("SKU-1");
+    let plate = Item::new("plate", [2, 2, 1])
+        .with_weight(2.0)
+        .with_data("SKU-2");
+    let levels = vec![
+        Level::new("carton", Bin::new([2, 2, 2])).with_tare(0.5),
+        Level::new("pallet", Bin::new([4, 4, 2])).with_padding(0.5),
+        Level::new("container", Bin::new([10, 5, 3])),
+    ];
+    let containers = pack_levels(&[mug.with_quantity(24), plate.with_quantity(6)], &levels)?;
+    assert_eq!(containers.len(), 1);
+    assert!(verify(&containers[0]).is_empty());
+
+    // Every item can be found by walking down from the container.
+    let found = skus(&containers[0]);
+    assert_eq!(found.len(), 30);
+    assert_eq!(found.iter().filter(|item| item.id == "plate").count(), 6);
+    assert!(found
+        .iter()
+        .all(|item| matches!(item.data, Contents::Sku("SKU-1") | Contents::Sku("SKU-2"))));
+
+    // Each packed bin's weight is what's inside of it, plus its tare.
+    for pallet in containers[0].items.iter() {
+        let pallet_bin = pallet.data.bin().unwrap();
+        assert_eq!(pallet.id, "pallet");
+        assert_eq!(pallet.padding, 0.5);
+        assert_eq!(pallet.weight, pallet_bin.total_weight());
+        for carton in pallet_bin.items.iter() {
+            let carton_bin = carton.data.bin().unwrap();
+            assert_eq!(carton.weight, carton_bin.total_weight() + 0.5);
+        }
+    }
+    assert_eq!(containers[0].total_weight(), 24.0 + 12.0 + 0.5 * 6.0);
+    Ok(())
+}
+
+#[test]
+fn test_no_levels() -> Result<()> {
+    let mug = Item::new("mug", [1, 1, 1]);
+    assert!(pack_levels::<()>(&[mug], &[])?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_packed_bin_too_big_for_next_level() {
+    let mug = Item::new("mug", [1, 1, 1]);
+    let levels = vec![
+        Level::new("carton", Bin::new([2, 2, 2]).with_wall_thickness(0.5)),
+        Level::new("pallet", Bin::new([2, 2, 2])),
+    ];
+    assert!(matches!(
+        pack_levels(&[mug], &levels),
+        Err(Error::AllItemsMustFit(_))
+    ));
+}
@@ -0,0 +1,75 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::knapsack::pack_knapsack;
+use bin_packer_3d::shipping::CarrierRule;
+use bin_packer_3d::verify::verify;
+
+/// test knapsack packing API
+
+#[test]
+fn test_value_defaults_to_volume() -> Result<()> {
+    let big = Item::new("big", [4, 4, 3]);
+    let small = Item::new("small", [2, 2, 2]);
+    let knapsack = pack_knapsack(Bin::new([4, 4, 4]), &[small.with_quantity(6), big])?;
+    assert_eq!(knapsack.chosen()[0].id, "big");
+    assert_eq!(knapsack.value(), 48.0);
+    assert_eq!(knapsack.rejected.len(), 6);
+    assert!(verify(&knapsack.bin).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_prefers_valuable_items() -> Result<()> {
+    let big = Item::new("big", [4, 4, 3]);
+    let small = Item::new("small", [2, 2, 2]).with_value(100.0);
+    let knapsack = pack_knapsack(Bin::new([4, 4, 4]), &[small.with_quantity(6), big])?;
+    assert_eq!(knapsack.chosen().len(), 6);
+    assert_eq!(knapsack.value(), 600.0);
+    assert_eq!(knapsack.rejected.len(), 1);
+    assert_eq!(knapsack.rejected[0].id, "big");
+    Ok(())
+}
+
+#[test]
+fn test_rejects_items_that_dont_fit() -> Result<()> {
+    let fits = Item::new("fits", [1, 1, 1]);
+    let too_big = Item::new("too_big", [5, 1, 1]).with_value(1_000.0);
+    let worthless = Item::new("worthless", [1, 1, 1]).with_value(0.0);
+    let knapsack = pack_knapsack(Bin::new([2, 2, 2]), &[too_big, fits, worthless])?;
+    assert_eq!(knapsack.chosen().len(), 1);
+    assert_eq!(knapsack.chosen()[0].id, "fits");
+    let mut rejected = knapsack
+        .rejected
+        .iter()
+        .map(|item| item.id)
+        .collect::<Vec<&str>>();
+    rejected.sort();
+    assert_eq!(rejected, vec!["too_big", "worthless"]);
+    Ok(())
+}
+
+#[test]
+fn test_respects_weight_limit() -> Result<()> {
+    let heavy = Item::new("heavy", [1, 1, 1])
+        .with_weight(10.0)
+        .with_value(5.0);
+    let light = Item::new("light", [1, 1, 1])
+        .with_weight(1.0)
+        .with_value(3.0);
+    let bin = Bin::new([2, 2, 2]).with_carrier_rules(&[CarrierRule::MaxWeight(12.0)]);
+    let knapsack = pack_knapsack(bin, &[heavy, light.with_quantity(4)])?;
+    assert_eq!(knapsack.value(), 12.0);
+    assert!(knapsack.chosen().iter().all(|item| item.id == "light"));
+    Ok(())
+}
+
+#[test]
+fn test_bin_breaking_carrier_rule() {
+    let item = Item::new("item", [1, 1, 1]);
+    let bin = Bin::new([2, 2, 2]).with_carrier_rules(&[CarrierRule::MaxSide(1.0)]);
+    assert!(matches!(
+        pack_knapsack(bin, &[item]),
+        Err(Error::CarrierRuleViolated(_))
+    ));
+}
@@ -0,0 +1,96 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::pack_bins;
+use bin_packer_3d::placement::Placement;
+use bin_packer_3d::verify::{verify, Violation};
+
+/// test obstruction API
+
+#[test]
+fn test_items_pack_around_obstructions() -> Result<()> {
+    let wheel_wells = [
+        Placement {
+            origin: [2.0, 0.0, 0.0],
+            dims: [2.0, 1.0, 1.0],
+        },
+        Placement {
+            origin: [2.0, 3.0, 0.0],
+            dims: [2.0, 1.0, 1.0],
+        },
+    ];
+    let van = Bin::new([6, 4, 2]).with_obstructions(&wheel_wells);
+    let item = Item::new("item", [1, 1, 1]);
+    let bins = pack_bins(van, &[item.with_quantity(44)])?;
+    assert_eq!(bins.len(), 1);
+    assert_eq!(bins[0].items.len(), 44);
+    assert!(verify(&bins[0]).is_empty());
+    assert!(!bins[0].fits(&item));
+    Ok(())
+}
+
+#[test]
+fn test_obstructions_with_gap_and_padding() -> Result<()> {
+    let obstruction = Placement {
+        origin: [3.0, 2.0, 0.0],
+        dims: [2.5, 3.0, 1.5],
+    };
+    let bin = Bin::new([10, 8, 6])
+        .with_gap(0.5)
+        .with_wall_clearance(0.25)
+        .with_min_support(0.5)
+        .with_obstructions(&[obstruction]);
+    let items = [
+        Item::new("small", [1.0, 1.5, 1.0]).with_quantity(20),
+        Item::new("padded", [2.0, 1.0, 1.0])
+            .with_padding(0.25)
+            .with_quantity(10),
+        Item::new("large", [3.0, 2.5, 2.0]).with_quantity(4),
+    ];
+    let bins = pack_bins(bin, &items)?;
+    assert!(bins.iter().all(|bin| verify(bin).is_empty()));
+    Ok(())
+}
+
+#[test]
+fn test_items_rest_on_obstructions() -> Result<()> {
+    let shelf = Placement {
+        origin: [0.0, 0.0, 0.0],
+        dims: [2.0, 2.0, 1.0],
+    };
+    let bin = Bin::new([2, 2, 3])
+        .with_min_support(1.0)
+        .with_obstructions(&[shelf]);
+    let bins = pack_bins(bin, &[Item::new("item", [2, 2, 1]).with_quantity(2)])?;
+    assert_eq!(bins.len(), 1);
+    assert_eq!(bins[0].support_ratios(), vec![1.0, 1.0]);
+    Ok(())
+}
+
+#[test]
+fn test_item_that_only_fits_without_the_obstruction() {
+    let obstruction = Placement {
+        origin: [1.0, 1.0, 1.0],
+        dims: [1.0, 1.0, 1.0],
+    };
+    let bin = Bin::new([3, 3, 3]).with_obstructions(&[obstruction]);
+    assert!(matches!(
+        pack_bins(bin, &[Item::new("item", [2, 2, 2])]),
+        Err(Error::AllItemsMustFit(_))
+    ));
+}
+
+#[test]
+fn test_verify_finds_obstructed_items() {
+    let obstruction = Placement {
+        origin: [0.0, 0.0, 0.0],
+        dims: [1.0, 1.0, 1.0],
+    };
+    let mut bin = Bin::new([2, 2, 2]).with_obstructions(&[obstruction]);
+    bin.items.push(Item::new("item", [1, 1, 1]));
+    bin.placements.push(Placement {
+        origin: [0.5, 0.0, 0.0],
+        dims: [1.0, 1.0, 1.0],
+    });
+    assert_eq!(verify(&bin), vec![Violation::Obstructed { item: 0 }]);
+}
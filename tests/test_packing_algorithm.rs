@@ -1,7 +1,8 @@
 use bin_packer_3d::bin::Bin;
 use bin_packer_3d::error::{Error, Result};
 use bin_packer_3d::item::{Item, ItemId};
-use bin_packer_3d::packing_algorithm::packing_algorithm;
+use bin_packer_3d::packing_algorithm::{pack_bins, pack_grouped, packing_algorithm};
+use bin_packer_3d::shipping::DimWeight;
 
 /// test packing_algorithm API
 
@@ -14,6 +15,7 @@ fn test_pack_items_no_items() -> Result<()> {
 }
 
 #[test]
+#[allow(clippy::useless_format)]
 fn test_pack_items_no_fit() -> Result<()> {
     let items = vec![Item::new("item1", [3, 4, 6])];
     let err = packing_algorithm(Bin::new([3, 4, 5]), &items).unwrap_err();
@@ -206,6 +208,71 @@ fn test_flat_bin() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_pack_bins_carries_payload() -> Result<()> {
+    // test that each item's payload is passed through untouched, even when ids repeat
+
+    let item_1 = Item::new("sku", [4, 4, 12]).with_data(("line-1", 10));
+    let item_2 = Item::new("sku", [4, 4, 12]).with_data(("line-2", 20));
+    let item_3 = Item::new("sku", [4, 4, 12]).with_data(("line-3", 30));
+    let bins = pack_bins(
+        Bin::new([4, 8, 12]).for_payload(),
+        &[item_1, item_2, item_3],
+    )?;
+    assert_eq!(
+        bins.iter()
+            .map(|bin| bin.items.iter().map(|item| item.data).collect())
+            .collect::<Vec<Vec<(&str, i32)>>>(),
+        vec![vec![("line-1", 10), ("line-2", 20)], vec![("line-3", 30)]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_item_quantity() -> Result<()> {
+    // test that an item with a quantity packs the same as repeating the item
+
+    let item = Item::new("item1", [1, 2, 3]);
+    let res = packing_algorithm(Bin::new([8, 9, 9]), &[item.with_quantity(107)])?;
+    assert_eq!(res, packing_algorithm(Bin::new([8, 9, 9]), &[item; 107])?);
+    Ok(())
+}
+
+#[test]
+fn test_pack_grouped_matches_packing_algorithm() -> Result<()> {
+    let deck = Item::new("deck", [2, 8, 12]).with_quantity(4);
+    let die = Item::new("die", [8, 8, 8]);
+    let res = pack_grouped(Bin::new([8, 8, 12]), &[die, deck])?;
+    assert_eq!(
+        res.iter()
+            .map(|bin| bin.iter().map(|item| (item.id, item.quantity)).collect())
+            .collect::<Vec<Vec<(&ItemId, usize)>>>(),
+        vec![vec![("deck", 4)], vec![("die", 1)]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pack_grouped_many_units() -> Result<()> {
+    // test that thousands of units of a single SKU are reported per bin
+
+    let sku_a = Item::new("SKU-A", [5, 5, 5]).with_quantity(5_000);
+    let sku_b = Item::new("SKU-B", [1, 1, 1]).with_quantity(3);
+    let res = pack_grouped(Bin::new([25, 10, 25]), &[sku_b, sku_a])?;
+    assert_eq!(res.len(), 101);
+    assert_eq!(
+        res.first()
+            .map(|bin| bin.iter().map(|item| (item.id, item.quantity)).collect()),
+        Some(vec![("SKU-A", 50)])
+    );
+    assert_eq!(
+        res.last()
+            .map(|bin| bin.iter().map(|item| (item.id, item.quantity)).collect()),
+        Some(vec![("SKU-B", 3)])
+    );
+    Ok(())
+}
+
 /// Test Bin API
 
 // NOTE: It's probably worth re-organizing our integration tests, perhaps grouping them by module.
@@ -228,3 +295,216 @@ fn test_bin_try_packing() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_bin_placements() -> Result<()> {
+    // test that items are stacked on top of each other along the vertical axis
+
+    let item = Item::new("item1", [4, 4, 2]);
+    let mut bin = Bin::new([4, 4, 4]);
+    assert!(bin.try_packing(item).is_some());
+    assert!(bin.try_packing(item).is_some());
+    assert_eq!(bin.placements[0].origin, [0.0, 0.0, 0.0]);
+    assert_eq!(bin.placements[0].dims, [4.0, 4.0, 2.0]);
+    assert_eq!(bin.placements[1].origin, [0.0, 0.0, 2.0]);
+    assert!(bin.placements[1].rests_on(&bin.placements[0], 0.0));
+    Ok(())
+}
+
+#[test]
+fn test_max_load_refuses_heavy_items_on_top() -> Result<()> {
+    let fragile = Item::new("fragile", [4, 4, 2])
+        .with_weight(1.0)
+        .with_max_load(5.0);
+    let heavy = Item::new("heavy", [4, 4, 2]).with_weight(10.0);
+    let light = Item::new("light", [4, 4, 2]).with_weight(2.0);
+    let res = packing_algorithm(Bin::new([4, 4, 4]), &[fragile, heavy, light])?;
+    assert_eq!(res, vec![vec!["fragile", "light"], vec!["heavy"]]);
+    Ok(())
+}
+
+#[test]
+fn test_max_load_includes_items_further_up() -> Result<()> {
+    let fragile = Item::new("fragile", [4, 4, 2])
+        .with_weight(1.0)
+        .with_max_load(5.0);
+    let item = Item::new("item", [4, 4, 2]).with_weight(3.0);
+    let mut bin = Bin::new([4, 4, 6]);
+    assert!(bin.try_packing(fragile).is_some());
+    assert!(bin.try_packing(item).is_some());
+    assert!(bin.try_packing(item).is_none());
+    assert_eq!(bin.loads(), vec![3.0, 0.0]);
+    Ok(())
+}
+
+#[test]
+fn test_loads_are_shared_between_supports() -> Result<()> {
+    let left = Item::new("left", [2, 2, 2]).with_weight(1.0);
+    let right = Item::new("right", [2, 2, 2]).with_weight(1.0);
+    let top = Item::new("top", [2, 4, 1]).with_weight(6.0);
+    let mut bin = Bin::new([4, 2, 3]);
+    assert!(bin.try_packing(left).is_some());
+    assert!(bin.try_packing(right).is_some());
+    assert!(bin.try_packing(top).is_some());
+    assert_eq!(bin.loads(), vec![3.0, 3.0, 0.0]);
+    Ok(())
+}
+
+#[test]
+fn test_max_stack() -> Result<()> {
+    let base = Item::new("base", [4, 4, 1]).with_max_stack(2);
+    let item = Item::new("item", [4, 4, 1]);
+    let mut bin = Bin::new([4, 4, 4]);
+    assert!(bin.try_packing(base).is_some());
+    assert!(bin.try_packing(item).is_some());
+    assert!(bin.try_packing(item).is_some());
+    assert!(bin.try_packing(item).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_min_support() -> Result<()> {
+    // test that items are not left floating when a minimum support is required
+
+    let item_1 = Item::new("item1", [1, 2, 3]);
+    let item_2 = Item::new("item2", [1, 3, 3]);
+    let items = vec![item_1, item_1, item_2];
+
+    let bins = pack_bins(Bin::new([5, 3, 4]), &items)?;
+    assert_eq!(bins.len(), 1);
+    assert_eq!(bins[0].support_ratios(), vec![1.0, 1.0, 1.0 / 3.0]);
+
+    let bins = pack_bins(Bin::new([5, 3, 4]).with_min_support(0.75), &items)?;
+    assert_eq!(bins.len(), 2);
+    assert!(bins
+        .iter()
+        .flat_map(|bin| bin.support_ratios())
+        .all(|ratio| ratio >= 0.75));
+    Ok(())
+}
+
+#[test]
+fn test_min_support_retries_skipped_items() -> Result<()> {
+    // test that an item which is skipped for lack of support is packed into the same bin once the
+    // items packed after it can support it
+
+    let items = vec![
+        Item::new("item1", [1, 2, 3]).with_quantity(5),
+        Item::new("item2", [2, 2, 2]).with_quantity(4),
+        Item::new("item3", [1, 2, 2]).with_quantity(2),
+    ];
+    let bins = pack_bins(Bin::new([6, 4, 4]).with_min_support(0.9), &items)?;
+    assert_eq!(bins.len(), 1);
+    assert!(bins[0].support_ratios().iter().all(|&ratio| ratio >= 0.9));
+    Ok(())
+}
+
+#[test]
+fn test_center_of_gravity() -> Result<()> {
+    let mut bin = Bin::new([4, 2, 2]);
+    assert_eq!(bin.center_of_gravity(), None);
+    assert!(bin.is_balanced(0.0));
+
+    assert!(bin
+        .try_packing(Item::new("heavy", [2, 2, 2]).with_weight(3.0))
+        .is_some());
+    assert!(bin
+        .try_packing(Item::new("light", [2, 2, 2]).with_weight(1.0))
+        .is_some());
+    assert_eq!(bin.center_of_gravity(), Some([1.5, 1.0, 1.0]));
+    assert_eq!(bin.cog_offset(), Some(0.5));
+    assert!(bin.is_balanced(0.5));
+    assert!(!bin.is_balanced(0.25));
+    Ok(())
+}
+
+#[test]
+fn test_balancing() -> Result<()> {
+    // test that balancing places items where they keep the centre of gravity closest to the centre
+
+    let heavy = Item::new("heavy", [2, 2, 1]).with_weight(5.0);
+    let light = Item::new("light", [2, 2, 1]).with_weight(1.0);
+
+    let mut unbalanced = Bin::new([6, 4, 1]);
+    let mut balanced = Bin::new([6, 4, 1]).with_balancing();
+    for item in [heavy, heavy, light] {
+        assert!(unbalanced.try_packing(item).is_some());
+        assert!(balanced.try_packing(item).is_some());
+    }
+    assert_eq!(unbalanced.placements[2].origin, [0.0, 2.0, 0.0]);
+    assert_eq!(balanced.placements[2].origin, [4.0, 0.0, 0.0]);
+    assert!(balanced.cog_offset() < unbalanced.cog_offset());
+    Ok(())
+}
+
+#[test]
+fn test_gap_between_items() -> Result<()> {
+    // test that the gap is kept beside items, but stacked items still rest on each other
+
+    let item = Item::new("item", [2, 2, 2]);
+    let bins = pack_bins(
+        Bin::new([5, 2, 4]).with_gap(1.0),
+        &[item, item, item, item, item],
+    )?;
+    assert_eq!(bins.len(), 2);
+    assert_eq!(
+        bins[0]
+            .placements
+            .iter()
+            .map(|placement| placement.origin)
+            .collect::<Vec<_>>(),
+        vec![
+            [0.0, 0.0, 0.0],
+            [3.0, 0.0, 0.0],
+            [0.0, 0.0, 2.0],
+            [3.0, 0.0, 2.0]
+        ]
+    );
+    assert_eq!(bins[0].support_ratios(), vec![1.0; 4]);
+    Ok(())
+}
+
+#[test]
+fn test_wall_clearance() -> Result<()> {
+    let item = Item::new("item", [2, 2, 2]);
+    assert!(!Bin::new([3, 3, 3]).with_wall_clearance(1.0).fits(&item));
+
+    let bins = pack_bins(Bin::new([4, 4, 4]).with_wall_clearance(1.0), &[item, item])?;
+    assert_eq!(bins.len(), 1);
+    assert_eq!(bins[0].placements[0].origin, [1.0, 1.0, 0.0]);
+    assert_eq!(bins[0].placements[1].origin, [1.0, 1.0, 2.0]);
+    Ok(())
+}
+
+#[test]
+fn test_item_padding() -> Result<()> {
+    // test that padded items are fitted with their padding, but report their true dimensions
+
+    let vase = Item::new("vase", [2, 2, 2]).with_padding(0.5);
+    let bins = pack_bins(Bin::new([6, 3, 3]), &[vase, vase, vase])?;
+    assert_eq!(bins.len(), 2);
+    assert_eq!(bins[0].placements[0].origin, [0.5, 0.5, 0.5]);
+    assert_eq!(bins[0].placements[0].dims, [2.0, 2.0, 2.0]);
+    assert_eq!(bins[0].placements[1].origin, [3.5, 0.5, 0.5]);
+    assert_eq!(bins[0].support_ratios(), vec![1.0, 1.0]);
+    Ok(())
+}
+
+#[test]
+fn test_inner_and_outer_dims() -> Result<()> {
+    // test that items are packed into the inner dimensions, and packed bins keep their outer
+    // dimensions for shipping
+
+    let item = Item::new("item", [10, 8, 3]);
+    let bin = Bin::new([10, 8, 6]).with_wall_thickness(0.5);
+    let bins = pack_bins(bin, &[item, item, item])?;
+    assert_eq!(bins.len(), 2);
+    assert!(bins.iter().all(|bin| bin.dims() == [10.0, 8.0, 6.0]));
+    assert!(bins.iter().all(|bin| bin.outer_dims() == [11.0, 9.0, 7.0]));
+    assert_eq!(DimWeight::new(693.0).dimensional_weight(&bins[0]), 1.0);
+
+    let bin = Bin::new([10, 8, 6]).with_outer_dims([12, 10, 6]);
+    assert!(bin.fits(&item));
+    assert!(!bin.fits(&Item::new("wide", [12, 10, 6])));
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::pack_bins;
+use bin_packer_3d::placement::Placement;
+use bin_packer_3d::sequence::{loading_sequence, Approach};
+
+/// test loading sequence API
+
+#[test]
+fn test_items_are_loaded_after_their_supports() -> Result<()> {
+    let item = Item::new("item", [4, 4, 1]);
+    let mut bin = Bin::new([4, 4, 3]);
+    bin.items = vec![item, item, item];
+    bin.placements = [2.0, 0.0, 1.0]
+        .iter()
+        .map(|&bottom| Placement {
+            origin: [0.0, 0.0, bottom],
+            dims: [4.0, 4.0, 1.0],
+        })
+        .collect();
+    assert_eq!(loading_sequence(&bin, Approach::Above, 0.0)?, vec![1, 2, 0]);
+    Ok(())
+}
+
+#[test]
+fn test_side_approach() -> Result<()> {
+    let item = Item::new("item", [2, 2, 2]);
+    let mut bin = Bin::new([4, 2, 2]);
+    bin.items = vec![item, item];
+    bin.placements = vec![
+        Placement {
+            origin: [2.0, 0.0, 0.0],
+            dims: [2.0, 2.0, 2.0],
+        },
+        Placement {
+            origin: [0.0, 0.0, 0.0],
+            dims: [2.0, 2.0, 2.0],
+        },
+    ];
+    assert_eq!(loading_sequence(&bin, Approach::Above, 0.0)?, vec![0, 1]);
+    assert_eq!(loading_sequence(&bin, Approach::Side(0), 0.0)?, vec![1, 0]);
+    Ok(())
+}
+
+#[test]
+fn test_side_approach_axis_must_be_valid() {
+    let mut bin = Bin::new([4, 2, 2]);
+    bin.try_packing(Item::new("item", [2, 2, 2]));
+    assert_eq!(
+        loading_sequence(&bin, Approach::Side(3), 0.0),
+        Err(Error::InvalidAxis(
+            "The approach axis is 3, but it must be 0, 1 or 2.".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_every_item_is_sequenced() -> Result<()> {
+    let item_1 = Item::new("item1", [1, 2, 3]);
+    let item_2 = Item::new("item2", [2, 2, 5]);
+    let items = vec![item_1.with_quantity(20), item_2.with_quantity(10)];
+    for bin in pack_bins(Bin::new([8, 9, 9]), &items)? {
+        let mut sequence = loading_sequence(&bin, Approach::Above, 0.0)?;
+
+        // every item is loaded after the items it rests on
+        let mut loaded = vec![false; bin.items.len()];
+        for &index in sequence.iter() {
+            assert!(bin
+                .placements
+                .iter()
+                .enumerate()
+                .filter(|(_, below)| bin.placements[index].rests_on(below, 0.0))
+                .all(|(below_index, _)| loaded[below_index]));
+            loaded[index] = true;
+        }
+
+        sequence.sort_unstable();
+        assert_eq!(sequence, (0..bin.items.len()).collect::<Vec<_>>());
+    }
+    Ok(())
+}
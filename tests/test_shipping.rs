@@ -0,0 +1,128 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::pack_bins;
+use bin_packer_3d::shipping::{CarrierRule, DimWeight};
+use bin_packer_3d::strategy::{best_packing, Objective, Strategy};
+use bin_packer_3d::verify::{verify, Violation};
+
+/// test shipping API
+
+#[test]
+fn test_dimensional_weight_uses_outer_dims() -> Result<()> {
+    let bin = Bin::new([9, 9, 9]).with_wall_thickness(0.5);
+    assert_eq!(DimWeight::new(100.0).dimensional_weight(&bin), 10.0);
+    Ok(())
+}
+
+#[test]
+fn test_side_rounding() -> Result<()> {
+    // test that sides are rounded up, but exact multiples are left alone
+
+    let bin = Bin::new([10.0, 10.2, 9.5]);
+    let rule = DimWeight::new(100.0).with_side_rounding(1.0);
+    assert_eq!(rule.dimensional_weight(&bin), 11.0);
+
+    let bin = Bin::new([0.1 + 0.2, 1.0, 1.0]);
+    let rule = DimWeight::new(1.0).with_side_rounding(0.1);
+    assert!((rule.dimensional_weight(&bin) - 0.3).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+fn test_billable_weight_is_the_higher_weight() -> Result<()> {
+    let rule = DimWeight::new(100.0).with_weight_rounding(0.5);
+    let light = Item::new("light", [5, 10, 10]).with_weight(1.0);
+    let heavy = Item::new("heavy", [5, 10, 10]).with_weight(20.2);
+
+    let bins = pack_bins(Bin::new([10, 10, 10]), &[light, heavy, light])?;
+    assert_eq!(bins.len(), 2);
+    assert_eq!(rule.billable_weight(&bins[0]), 21.5);
+    assert_eq!(rule.billable_weight(&bins[1]), 10.0);
+    assert_eq!(rule.total_billable_weight(&bins), 31.5);
+    Ok(())
+}
+
+#[test]
+fn test_billable_weight_objective() -> Result<()> {
+    let rule = DimWeight::new(100.0);
+    let item = Item::new("item", [5, 10, 10]).with_weight(1.0);
+    let items = vec![item.with_quantity(3)];
+
+    let (_, bins) = best_packing(
+        Bin::new([10, 10, 10]),
+        &items,
+        &Strategy::all(),
+        Objective::BillableWeight(rule),
+    )?;
+    assert_eq!(bins.len(), 2);
+    assert_eq!(Objective::BillableWeight(rule).score(&bins), 20.0);
+    Ok(())
+}
+
+#[test]
+fn test_carrier_rules() -> Result<()> {
+    let bin = Bin::new([40, 30, 20]).with_wall_thickness(0.5);
+    assert!(CarrierRule::MaxSide(41.0).is_met_by(&bin));
+    assert!(!CarrierRule::MaxSide(40.0).is_met_by(&bin));
+    assert!(CarrierRule::MaxLengthPlusGirth(145.0).is_met_by(&bin));
+    assert!(!CarrierRule::MaxLengthPlusGirth(144.0).is_met_by(&bin));
+    assert!(CarrierRule::MaxVolume(41.0 * 31.0 * 21.0).is_met_by(&bin));
+    assert!(!CarrierRule::MaxVolume(40.0 * 30.0 * 20.0).is_met_by(&bin));
+    Ok(())
+}
+
+#[test]
+fn test_oversized_bin_is_an_error() -> Result<()> {
+    let item = Item::new("item", [10, 10, 10]);
+    let bin = Bin::new([100, 40, 20]).with_carrier_rules(&[
+        CarrierRule::MaxWeight(70.0),
+        CarrierRule::MaxLengthPlusGirth(165.0),
+    ]);
+    assert_eq!(
+        pack_bins(bin, &[item]).map(|bins| bins.len()),
+        Err(Error::CarrierRuleViolated(
+            "The bin breaks the carrier's max length + girth of 165.".to_string()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_max_weight_opens_new_bins() -> Result<()> {
+    let item = Item::new("item", [10, 10, 10]).with_weight(30.0);
+    let bin = Bin::new([20, 20, 20]).with_carrier_rules(&[CarrierRule::MaxWeight(70.0)]);
+    let bins = pack_bins(bin.clone_as_empty_bin(), &[item.with_quantity(8)])?;
+    assert_eq!(
+        bins.iter().map(|bin| bin.items.len()).collect::<Vec<_>>(),
+        vec![2, 2, 2, 2]
+    );
+    assert!(bins.iter().all(|bin| verify(bin).is_empty()));
+
+    let anvil = Item::new("anvil", [10, 10, 10]).with_weight(80.0);
+    assert_eq!(
+        pack_bins(bin, &[item, anvil]).map(|bins| bins.len()),
+        Err(Error::CarrierRuleViolated(
+            "The item `anvil` is too heavy for the carrier's max weight of 70.".to_string()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_verify_finds_broken_carrier_rules() -> Result<()> {
+    let mut bin = Bin::new([20, 20, 20]).with_carrier_rules(&[CarrierRule::MaxWeight(70.0)]);
+    bin.try_packing(Item::new("item", [10, 10, 10]).with_weight(30.0));
+    bin.items[0].weight = 80.0;
+    assert_eq!(
+        bin.broken_carrier_rule(),
+        Some(CarrierRule::MaxWeight(70.0))
+    );
+    assert_eq!(
+        verify(&bin),
+        vec![Violation::BreaksCarrierRule {
+            rule: CarrierRule::MaxWeight(70.0)
+        }]
+    );
+    Ok(())
+}
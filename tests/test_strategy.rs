@@ -0,0 +1,168 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::Result;
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::{pack_bins_with_strategy, packing_algorithm};
+use bin_packer_3d::strategy::{best_packing, Algorithm, Objective, SortKey, Strategy};
+
+/// test strategy API
+
+#[test]
+fn test_default_strategy_matches_packing_algorithm() -> Result<()> {
+    let item_1 = Item::new("item1", [3, 8, 10]);
+    let item_2 = Item::new("item2", [1, 2, 5]);
+    let item_3 = Item::new("item3", [1, 2, 2]);
+    let items = vec![item_3, item_2, item_1, item_2];
+    let bins = pack_bins_with_strategy(Bin::new([10, 20, 20]), &items, &Strategy::default())?;
+    assert_eq!(
+        bins.into_iter()
+            .map(|bin| bin.items.into_iter().map(|item| item.id).collect())
+            .collect::<Vec<Vec<&str>>>(),
+        packing_algorithm(Bin::new([10, 20, 20]), &items)?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_all_bins_open() -> Result<()> {
+    // test that a later item can be packed into an earlier bin when all bins are kept open
+
+    let big = Item::new("big", [4, 4, 8]);
+    let small = Item::new("small", [4, 4, 4]);
+    let strategy = Strategy {
+        algorithm: Algorithm::AllBinsOpen,
+        ..Strategy::default()
+    };
+    let bins = pack_bins_with_strategy(Bin::new([4, 4, 12]), &[big, big, small, small], &strategy)?;
+    assert_eq!(
+        bins.into_iter()
+            .map(|bin| bin.items.into_iter().map(|item| item.id).collect())
+            .collect::<Vec<Vec<&str>>>(),
+        vec![vec!["big", "small"], vec!["big", "small"]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sort_by_volume() -> Result<()> {
+    let long = Item::new("long", [1, 1, 10]);
+    let cube = Item::new("cube", [5, 5, 5]);
+    let strategy = Strategy {
+        sort_key: SortKey::Volume,
+        ..Strategy::default()
+    };
+    let bins = pack_bins_with_strategy(Bin::new([10, 10, 10]), &[long, cube], &strategy)?;
+    assert_eq!(
+        bins[0]
+            .items
+            .iter()
+            .map(|item| item.id)
+            .collect::<Vec<&str>>(),
+        vec!["cube", "long"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_strategy_tolerance() -> Result<()> {
+    // test that the strategy's tolerance lets inexact dimensions fit
+
+    let item = Item::new("item1", [0.1 + 0.2, 1.0, 1.0]);
+    let strategy = Strategy {
+        tolerance: Some(1e-9),
+        ..Strategy::default()
+    };
+    assert!(packing_algorithm(Bin::new([0.3, 1.0, 1.0]), &[item]).is_err());
+    let bins = pack_bins_with_strategy(Bin::new([0.3, 1.0, 1.0]), &[item], &strategy)?;
+    assert_eq!(bins.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_best_packing_picks_fewest_bins() -> Result<()> {
+    // test that a strategy which needs fewer bins than the default is picked
+
+    let item_1 = Item::new("item1", [2, 4, 6]);
+    let item_2 = Item::new("item2", [2, 3, 5]);
+    let item_3 = Item::new("item3", [2, 3, 8]);
+    let items = vec![item_1, item_2, item_3];
+    assert_eq!(packing_algorithm(Bin::new([6, 4, 9]), &items)?.len(), 2);
+    let (strategy, bins) = best_packing(
+        Bin::new([6, 4, 9]),
+        &items,
+        &Strategy::all(),
+        Objective::FewestBins,
+    )?;
+    assert_eq!(bins.len(), 1);
+    assert_eq!(strategy.sort_key, SortKey::Volume);
+    Ok(())
+}
+
+#[test]
+fn test_best_packing_prefers_first_strategy_on_ties() -> Result<()> {
+    let item = Item::new("item1", [4, 4, 12]);
+    let (strategy, _) = best_packing(
+        Bin::new([4, 4, 12]),
+        &[item, item],
+        &Strategy::all(),
+        Objective::FewestBins,
+    )?;
+    assert_eq!(strategy, Strategy::default());
+    Ok(())
+}
+
+#[test]
+fn test_balance_objective() -> Result<()> {
+    // test that balance only breaks ties between packings with the same number of bins
+
+    let heavy = Item::new("heavy", [2, 2, 1]).with_weight(5.0);
+    let light = Item::new("light", [2, 2, 1]).with_weight(1.0);
+    let items = vec![heavy, heavy, light];
+
+    let unbalanced = pack_bins_with_strategy(Bin::new([6, 4, 1]), &items, &Strategy::default())?;
+    let balanced = pack_bins_with_strategy(
+        Bin::new([6, 4, 1]).with_balancing(),
+        &items,
+        &Strategy::default(),
+    )?;
+    let two_bins = pack_bins_with_strategy(
+        Bin::new([2, 2, 1]).with_balancing(),
+        &items[..2],
+        &Strategy::default(),
+    )?;
+
+    assert_eq!(Objective::FewestBins.score(&balanced), 1.0);
+    assert!(Objective::Balance.score(&balanced) < Objective::Balance.score(&unbalanced));
+    assert!(Objective::Balance.score(&unbalanced) < Objective::Balance.score(&two_bins));
+    Ok(())
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_best_packing_is_independent_of_thread_count() -> Result<()> {
+    let item_1 = Item::new("item1", [1, 2, 3]);
+    let item_2 = Item::new("item2", [2, 2, 5]);
+    let items = vec![item_1.with_quantity(60), item_2.with_quantity(15)];
+    let pack_with_threads = |threads| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("failed to build the thread pool")
+            .install(|| {
+                best_packing(
+                    Bin::new([8, 9, 9]),
+                    &items,
+                    &Strategy::all(),
+                    Objective::FewestBins,
+                )
+                .map(|(strategy, bins)| {
+                    let ids = bins
+                        .into_iter()
+                        .map(|bin| bin.items.into_iter().map(|item| item.id).collect())
+                        .collect::<Vec<Vec<&str>>>();
+                    (strategy, ids)
+                })
+            })
+    };
+    assert_eq!(pack_with_threads(1)?, pack_with_threads(4)?);
+    Ok(())
+}
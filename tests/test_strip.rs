@@ -0,0 +1,84 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::shipping::CarrierRule;
+use bin_packer_3d::strip::pack_strip;
+use bin_packer_3d::verify::verify;
+
+/// test strip packing API
+
+#[test]
+fn test_minimise_height() -> Result<()> {
+    let item_1 = Item::new("item1", [4, 4, 1]);
+    let item_2 = Item::new("item2", [2, 4, 2]);
+    let packing = pack_strip(
+        Bin::new([4, 4, 0]),
+        2,
+        &[item_1.with_quantity(3), item_2, item_2],
+    )?;
+    assert_eq!(packing.length, 5.0);
+    assert_eq!(packing.bin.dims(), [4.0, 4.0, 5.0]);
+    assert_eq!(packing.bin.items.len(), 5);
+    assert!(verify(&packing.bin).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_length_includes_margins() -> Result<()> {
+    let item = Item::new("item", [2, 2, 2]);
+    let bin = Bin::new([0, 2, 2]).with_gap(1.0).with_wall_clearance(0.5);
+    assert!(pack_strip(bin.clone_as_empty_bin(), 0, &[item]).is_err());
+
+    let bin = Bin::new([0, 3, 2]).with_gap(1.0).with_wall_clearance(0.5);
+    let packing = pack_strip(bin, 0, &[item.with_quantity(3)])?;
+    assert_eq!(packing.length, 9.0);
+    assert!(verify(&packing.bin).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_strip_items_must_fit_across() -> Result<()> {
+    let item = Item::new("item", [3, 3, 3]);
+    assert_eq!(
+        pack_strip(Bin::new([2, 2, 0]), 2, &[item]).map(|packing| packing.length),
+        Err(Error::AllItemsMustFit(
+            "All items must fit within the bin dimensions.".to_string()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_strip_open_axis_must_be_valid() {
+    let item = Item::new("item", [1, 1, 1]);
+    assert_eq!(
+        pack_strip(Bin::new([2, 2, 0]), 3, &[item]).map(|packing| packing.length),
+        Err(Error::InvalidAxis(
+            "The open axis is 3, but it must be 0, 1 or 2.".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_empty_strip() -> Result<()> {
+    let items = vec![];
+    let packing = pack_strip(Bin::new([2, 2, 0]), 2, &items)?;
+    assert_eq!(packing.length, 0.0);
+    assert!(packing.bin.items.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_strip_carrier_rules_apply_to_the_final_length() -> Result<()> {
+    let item = Item::new("item", [1, 1, 1]);
+    let bin = Bin::new([0, 1, 1]).with_carrier_rules(&[CarrierRule::MaxSide(3.0)]);
+    let packing = pack_strip(bin.clone_as_empty_bin(), 0, &[item.with_quantity(3)])?;
+    assert_eq!(packing.length, 3.0);
+    assert_eq!(
+        pack_strip(bin, 0, &[item.with_quantity(4)]).map(|packing| packing.length),
+        Err(Error::CarrierRuleViolated(
+            "The packed bin breaks the carrier's max side of 3.".to_string()
+        ))
+    );
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use bin_packer_3d::bin::Bin;
+use bin_packer_3d::error::{Error, Result};
+use bin_packer_3d::item::Item;
+use bin_packer_3d::packing_algorithm::{pack_bins, pack_grouped};
+use bin_packer_3d::placement::Placement;
+use bin_packer_3d::verify::{verify, Violation};
+
+/// test delivery stops and the verifier
+
+#[test]
+fn test_delivery_stops_are_loaded_in_reverse() -> Result<()> {
+    let items = vec![
+        Item::new("stop1", [2, 2, 2]).with_delivery_stop(1),
+        Item::new("stop2", [2, 2, 2]).with_delivery_stop(2),
+        Item::new("stop3", [2, 2, 2]).with_delivery_stop(3),
+    ];
+    let bins = pack_bins(Bin::new([6, 2, 2]).with_loading_axis(0), &items)?;
+    assert_eq!(bins.len(), 1);
+    assert_eq!(
+        bins[0].items.iter().map(|item| item.id).collect::<Vec<_>>(),
+        vec!["stop3", "stop2", "stop1"]
+    );
+    assert_eq!(
+        bins[0]
+            .placements
+            .iter()
+            .map(|placement| placement.origin[0])
+            .collect::<Vec<_>>(),
+        vec![0.0, 2.0, 4.0]
+    );
+    assert_eq!(verify(&bins[0]), vec![]);
+    Ok(())
+}
+
+#[test]
+fn test_grouped_delivery_stops_are_loaded_in_reverse() -> Result<()> {
+    let items = vec![
+        Item::new("stop1", [2, 2, 2]).with_delivery_stop(1),
+        Item::new("stop2", [2, 2, 2])
+            .with_delivery_stop(2)
+            .with_quantity(2),
+    ];
+    let bins = pack_grouped(Bin::new([6, 2, 2]).with_loading_axis(0), &items)?;
+    assert_eq!(
+        bins.iter()
+            .map(|bin| bin.iter().map(|item| (item.id, item.quantity)).collect())
+            .collect::<Vec<Vec<(&str, usize)>>>(),
+        vec![vec![("stop2", 2), ("stop1", 1)]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_loading_axis() {
+    let item = Item::new("item", [2, 2, 2]).with_delivery_stop(1);
+    let bins = pack_bins(Bin::new([6, 2, 2]).with_loading_axis(3), &[item]);
+    assert!(matches!(bins, Err(Error::InvalidAxis(_))));
+
+    let mut bin = Bin::new([6, 2, 2]).with_loading_axis(3);
+    assert!(bin.try_packing(item).is_some());
+    assert!(verify(&bin).is_empty());
+}
+
+#[test]
+fn test_later_stops_are_not_stacked_on_earlier_stops() -> Result<()> {
+    // test that items for later stops aren't placed on top of items for earlier stops, even when
+    // they're packed afterwards
+
+    let first = Item::new("first", [2, 2, 2]).with_delivery_stop(1);
+    let last = Item::new("last", [2, 2, 2]).with_delivery_stop(2);
+    let mut bin = Bin::new([2, 2, 4]).with_loading_axis(0);
+    assert!(bin.try_packing(first).is_some());
+    assert!(bin.try_packing(last).is_none());
+
+    let bins = pack_bins(
+        Bin::new([2, 2, 4]).with_loading_axis(0),
+        &[first, last, first],
+    )?;
+    assert_eq!(bins.len(), 2);
+    assert!(bins.iter().all(|bin| verify(bin).is_empty()));
+    Ok(())
+}
+
+#[test]
+fn test_packed_bins_have_no_violations() -> Result<()> {
+    let item_1 = Item::new("item1", [1, 2, 3])
+        .with_weight(2.0)
+        .with_max_load(4.0);
+    let item_2 = Item::new("item2", [2, 2, 5])
+        .with_weight(1.0)
+        .with_max_stack(1);
+    let items = vec![item_1.with_quantity(20), item_2.with_quantity(10)];
+    let bins = pack_bins(Bin::new([8, 9, 9]).with_min_support(0.5), &items)?;
+    assert!(bins.iter().all(|bin| verify(bin).is_empty()));
+    Ok(())
+}
+
+#[test]
+fn test_verify_finds_violations() -> Result<()> {
+    let mut bin = Bin::new([4, 4, 4]).with_loading_axis(1);
+    let eggs = Item::new("eggs", [4, 4, 2])
+        .with_max_load(1.0)
+        .with_delivery_stop(1);
+    let anvil = Item::new("anvil", [4, 4, 2])
+        .with_weight(5.0)
+        .with_delivery_stop(2);
+
+    bin.items = vec![eggs, anvil, anvil];
+    bin.placements = vec![
+        Placement {
+            origin: [0.0, 0.0, 0.0],
+            dims: [4.0, 4.0, 2.0],
+        },
+        Placement {
+            origin: [0.0, 0.0, 2.0],
+            dims: [4.0, 4.0, 2.0],
+        },
+        Placement {
+            origin: [1.0, 0.0, 2.0],
+            dims: [4.0, 4.0, 2.0],
+        },
+    ];
+    assert_eq!(
+        verify(&bin),
+        vec![
+            Violation::OutOfBounds { item: 2 },
+            Violation::Overlap {
+                first: 1,
+                second: 2
+            },
+            Violation::Overloaded { item: 0 },
+            Violation::BlocksUnloading {
+                blocking: 1,
+                blocked: 0
+            },
+            Violation::BlocksUnloading {
+                blocking: 2,
+                blocked: 0
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_verify_accounts_for_margins() -> Result<()> {
+    let item = Item::new("item", [1, 1, 1]).with_padding(0.5);
+    let mut bin = Bin::new([5, 2, 2]).with_gap(1.0).with_wall_clearance(0.5);
+    bin.items = vec![item, item];
+    bin.placements = vec![
+        Placement {
+            origin: [0.5, 0.5, 0.5],
+            dims: [1.0, 1.0, 1.0],
+        },
+        Placement {
+            origin: [2.5, 1.0, 0.5],
+            dims: [1.0, 1.0, 1.0],
+        },
+    ];
+    assert_eq!(
+        verify(&bin),
+        vec![
+            Violation::OutOfBounds { item: 0 },
+            Violation::OutOfBounds { item: 1 },
+            Violation::Overlap {
+                first: 0,
+                second: 1
+            },
+        ]
+    );
+
+    let bins = pack_bins(
+        Bin::new([5, 3, 3]).with_gap(1.0).with_wall_clearance(0.5),
+        &[item.with_quantity(4)],
+    )?;
+    assert!(bins.iter().all(|bin| verify(bin).is_empty()));
+    Ok(())
+}
+
+#[test]
+fn test_verify_finds_mismatched_placements() -> Result<()> {
+    let mut bin = Bin::new([4, 4, 4]);
+    bin.try_packing(Item::new("item1", [4, 4, 2]).with_max_load(1.0));
+    bin.items
+        .push(Item::new("item2", [4, 4, 2]).with_max_stack(0));
+    assert_eq!(verify(&bin), vec![Violation::MissingPlacement { item: 1 }]);
+
+    bin.items.pop();
+    bin.placements.push(Placement {
+        origin: [0.0, 0.0, 2.0],
+        dims: [4.0, 4.0, 2.0],
+    });
+    assert_eq!(verify(&bin), vec![Violation::MissingItem { placement: 1 }]);
+    Ok(())
+}